@@ -10,7 +10,7 @@ use std::sync::Arc;
 use metashrew_support::index_pointer::KeyValuePointer;
 use metashrew_support::compat::to_arraybuffer_layout;
 
-/// Orbital template ID - this is the template used for creating orbital instances
+/// Default orbital template ID - used when a collection is initialized without one
 pub const ORBITAL_TEMPLATE_ID: u128 = 0xe0e2;
 
 /// Bitcoin Collection alkane that acts as a factory for orbital instances
@@ -24,12 +24,12 @@ enum BitcoinCollectionMessage {
     /// Initialize the collection
     #[opcode(0)]
     Initialize {
-        /// Name part 1
-        name_part1: u128,
-        /// Name part 2
-        name_part2: u128,
-        /// Symbol
-        symbol: u128,
+        /// Orbital template ID (0 for the default template)
+        orbital_template: u128,
+        /// Name (length-prefixed string)
+        name: String,
+        /// Symbol (length-prefixed string)
+        symbol: String,
     },
 
     /// Create a new orbital instance (only callable by authorized alkanes)
@@ -60,6 +60,31 @@ enum BitcoinCollectionMessage {
     #[opcode(1000)]
     #[returns(Vec<u8>)]
     GetData,
+
+    /// Get the size of the collection data in bytes
+    #[opcode(1001)]
+    #[returns(u128)]
+    GetDataSize,
+
+    /// Get the bytes of the collection data in [offset, offset + length)
+    #[opcode(1002)]
+    #[returns(Vec<u8>)]
+    GetDataRange {
+        /// Offset of the first byte
+        offset: u128,
+        /// Number of bytes to return, clamped to the end of the data
+        length: u128,
+    },
+
+    /// Get the MIME type of the collection data
+    #[opcode(1003)]
+    #[returns(String)]
+    GetContentType,
+
+    /// Get the SHA-256 hash of the collection data
+    #[opcode(1004)]
+    #[returns(Vec<u8>)]
+    GetContentHash,
 }
 
 impl Token for BitcoinCollection {
@@ -95,6 +120,16 @@ impl BitcoinCollection {
         self.name_pointer().set(Arc::new(name.as_bytes().to_vec()));
     }
 
+    /// Get the pointer to the orbital template ID
+    fn orbital_template_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/orbital-template")
+    }
+
+    /// Set the orbital template ID, where 0 selects the default template
+    fn set_orbital_template_id(&self, template: u128) {
+        self.orbital_template_pointer().set_value::<u128>(template);
+    }
+
     /// Get the pointer to the symbol
     fn symbol_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/symbol")
@@ -177,7 +212,10 @@ impl BitcoinCollection {
 impl OrbitalCollection for BitcoinCollection {
     /// Get the orbital template ID
     fn orbital_template(&self) -> u128 {
-        ORBITAL_TEMPLATE_ID
+        match self.orbital_template_pointer().get_value::<u128>() {
+            0 => ORBITAL_TEMPLATE_ID,
+            template => template,
+        }
     }
     
     /// Check if an alkane ID is authorized to create orbitals
@@ -191,23 +229,19 @@ impl OrbitalCollection for BitcoinCollection {
     }
     
     /// Initialize the collection
-    fn initialize(&self, name_part1: u128, name_part2: u128, symbol: u128) -> Result<CallResponse> {
+    fn initialize(&self, orbital_template: u128, name: String, symbol: String) -> Result<CallResponse> {
         let context = self.context()?;
         let response = CallResponse::forward(&context.incoming_alkanes);
 
         // Prevent multiple initializations
         self.observe_initialization()?;
 
-        // Set the name
-        let name = format!("{}{}", 
-            String::from_utf8_lossy(&name_part1.to_le_bytes()).trim_matches(char::from(0)),
-            String::from_utf8_lossy(&name_part2.to_le_bytes()).trim_matches(char::from(0))
-        );
+        // Set the name and symbol
         self.set_name(&name);
+        self.set_symbol(&symbol);
 
-        // Set the symbol
-        let symbol_str = String::from_utf8_lossy(&symbol.to_le_bytes()).trim_matches(char::from(0)).to_string();
-        self.set_symbol(&symbol_str);
+        // Set the orbital template used for new mints
+        self.set_orbital_template_id(orbital_template);
 
         // Initialize the instances count
         self.set_instances_count(0);
@@ -290,6 +324,50 @@ impl OrbitalCollection for BitcoinCollection {
 
         Ok(response)
     }
+
+    /// Get the size of the collection data
+    fn get_data_size(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Matches the empty data returned by get_data
+        response.data = 0u128.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get a range of the collection data
+    fn get_data_range(&self, _offset: u128, _length: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Every range of the empty data is empty
+        response.data = Vec::new();
+
+        Ok(response)
+    }
+
+    /// Get the MIME type of the collection data
+    fn get_content_type(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // The empty data has no declared type
+        response.data = Vec::new();
+
+        Ok(response)
+    }
+
+    /// Get the SHA-256 hash of the collection data
+    fn get_content_hash(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Matches the empty data returned by get_data
+        response.data = orbitals_support::content_hash(&[]).to_vec();
+
+        Ok(response)
+    }
 }
 
 // Use the declare_orbital_collection macro
@@ -1,6 +1,6 @@
 use alkanes_runtime::declare_alkane;
 use alkanes_runtime::message::MessageDispatch;
-use alkanes_runtime::{runtime::AlkaneResponder, storage::StoragePointer};
+use alkanes_runtime::{auth::AuthenticatedResponder, runtime::AlkaneResponder, storage::StoragePointer};
 use alkanes_support::{parcel::AlkaneTransfer, response::CallResponse};
 use anyhow::{anyhow, Result};
 use metashrew_support::compat::to_arraybuffer_layout;
@@ -14,6 +14,16 @@ use bitcoin::{Script, TxOut, Transaction};
 use bitcoin::hashes::Hash;
 use metashrew_support::utils::consensus_decode;
 use protorune_support::network::{to_address_str, NetworkParams, set_network};
+use orbitals_support::buyer::{buyer_key, buyer_key_from_inputs, pointer_script, BuyerKey};
+use orbitals_support::codec::{encode_string, InputReader};
+use orbitals_support::config::{config_fields, digest_detail, ConfigChange, NO_DETAIL};
+use orbitals_support::merkle::{self, MerkleHash};
+use orbitals_support::purchase::{mint_allowance, PurchaseOptions};
+use orbitals_support::referral::referrer_script;
+use orbitals_support::split::{share_of, split_shares, validate_shares, BPS_DENOMINATOR, MAX_BENEFICIARIES};
+use orbitals_support::terms::{accepted_version, encode_history, TermsVersion};
+use orbitals_support::voucher::Voucher;
+use bitcoin::secp256k1::XOnlyPublicKey;
 
 /// BitcoinSale alkane for selling orbital instances using BTC payments
 #[derive(Default)]
@@ -26,6 +36,15 @@ pub const BITCOIN_COLLECTION_TEMPLATE_ID: u128 = 0xe0e4;
 #[derive(MessageDispatch)]
 enum BitcoinSaleMessage {
     /// Initialize the sale
+    /// Followed by the collection name and symbol as length-prefixed strings, then optionally
+    /// the maximum orbitals per purchase and per buyer (0 or omitted for no cap), the
+    /// voucher signer's x-only public key as two u128 inputs (0,0 or omitted for no vouchers)
+    /// the number of other beneficiaries followed by each one's three taproot parts and
+    /// share in basis points, the referrer's cut in basis points (0 or omitted for no
+    /// referrals), and the terms of service: 1 to require buyers to accept them (0 otherwise)
+    /// followed by a kind from `orbitals_support::terms::terms_kinds` and the terms (omitted
+    /// for the default terms), which UpdateTerms can only replace while purchases are paused
+    /// because they open with the sale. The taproot address receives the rest of each payment.
     #[opcode(0)]
     Initialize {
         /// Price per orbital in satoshis
@@ -38,15 +57,64 @@ enum BitcoinSaleMessage {
         taproot_part2: u128,
         /// Taproot address part 3 (last 12 bytes)
         taproot_part3: u128,
-        /// Collection name part 1
-        name_part1: u128,
-        /// Collection name part 2
-        name_part2: u128,
-        /// Collection symbol
-        symbol: u128,
+        /// Orbital template ID for the collection (0 for the default template)
+        orbital_template: u128,
+    },
+
+    /// Set the Merkle allowlist root and the block height the allowlist phase ends at
+    /// (requires the auth token; an end height of 0 turns the allowlist off)
+    #[opcode(12)]
+    SetAllowlist {
+        /// Low half of the root
+        root_low: u128,
+        /// High half of the root
+        root_high: u128,
+        /// Block height the allowlist phase ends at (exclusive)
+        end_height: u128,
+    },
+
+    /// Change the price per orbital in satoshis (requires the auth token)
+    #[opcode(15)]
+    UpdatePrice {
+        /// New price per orbital
+        price: u128,
+    },
+
+    /// Change the maximum number of orbitals that can be sold (requires the auth token)
+    #[opcode(16)]
+    UpdateLimit {
+        /// New limit (0 for no limit)
+        limit: u128,
+    },
+
+    /// Change the taproot address payments go to (requires the auth token)
+    /// Optionally followed by the other beneficiaries in the same form as Initialize, which
+    /// replace the current ones; without them the other beneficiaries are kept
+    #[opcode(17)]
+    UpdateBeneficiary {
+        /// Taproot address part 1 (first 10 bytes)
+        taproot_part1: u128,
+        /// Taproot address part 2 (next 10 bytes)
+        taproot_part2: u128,
+        /// Taproot address part 3 (last 12 bytes)
+        taproot_part3: u128,
+    },
+
+    /// Publish a new version of the terms of service (requires the auth token)
+    /// Followed by a kind from `orbitals_support::terms::terms_kinds` and the terms; only
+    /// allowed while purchases are paused
+    #[opcode(18)]
+    UpdateTerms,
+
+    /// Pause or resume purchases (requires the auth token)
+    #[opcode(19)]
+    SetPaused {
+        /// 1 to pause purchases, 0 to resume them
+        paused: u128,
     },
 
     /// Purchase an orbital
+    /// Optionally followed by tagged sections from `orbitals_support::purchase::purchase_tags`
     #[opcode(77)]
     Purchase,
 
@@ -70,7 +138,7 @@ enum BitcoinSaleMessage {
     #[returns(u128)]
     GetSold,
 
-    /// Get the terms of service
+    /// Get the current terms of service: the text, or the URI of hash-committed terms
     #[opcode(104)]
     #[returns(String)]
     GetTermsOfService,
@@ -79,7 +147,80 @@ enum BitcoinSaleMessage {
     #[opcode(105)]
     #[returns(String)]
     GetTaprootAddress,
+
+    /// Get how many orbitals a buyer can still purchase in one transaction
+    #[opcode(110)]
+    #[returns(u128)]
+    GetAllowance {
+        /// Low half of the buyer key
+        key_low: u128,
+        /// High half of the buyer key
+        key_high: u128,
+    },
+
+    /// Get the Merkle allowlist root followed by the block height the allowlist phase ends at
+    #[opcode(111)]
+    #[returns(Vec<u8>)]
+    GetAllowlist,
+
+    /// Get the x-only public key that signs mint vouchers
+    #[opcode(112)]
+    #[returns(Vec<u8>)]
+    GetVoucherSigner,
+
+    /// Check if a voucher nonce has been used (1 if used, 0 otherwise)
+    #[opcode(113)]
+    #[returns(u128)]
+    GetNonceUsed {
+        /// Voucher nonce
+        nonce: u128,
+    },
     
+    /// Get each beneficiary's address and share in basis points, one per line,
+    /// starting with the taproot address
+    #[opcode(115)]
+    #[returns(String)]
+    GetBeneficiaries,
+
+    /// Get a referrer's stats as u128 values: the referral cut in basis points, the number of
+    /// referred purchases and orbitals and the satoshis the referrer was paid
+    #[opcode(117)]
+    #[returns(Vec<u8>)]
+    GetReferralStats {
+        /// Low half of the referrer key
+        key_low: u128,
+        /// High half of the referrer key
+        key_high: u128,
+    },
+
+    /// Get every configuration change: the number of changes as a u128 followed by each
+    /// change's block height, field from `orbitals_support::config::config_fields` and new
+    /// value as u128s and its 32-byte detail
+    #[opcode(119)]
+    #[returns(Vec<u8>)]
+    GetConfigLog,
+
+    /// Check if purchases are paused (1 if paused, 0 otherwise)
+    #[opcode(120)]
+    #[returns(u128)]
+    GetPaused,
+
+    /// Get every version of the terms of service: the number of versions followed by each
+    /// version's hash, block height, kind, body length as a u128 and body
+    #[opcode(121)]
+    #[returns(Vec<u8>)]
+    GetTermsHistory,
+
+    /// Get the terms version a buyer last accepted (0 if they have not accepted any)
+    #[opcode(122)]
+    #[returns(u128)]
+    GetTermsAccepted {
+        /// Low half of the buyer key
+        key_low: u128,
+        /// High half of the buyer key
+        key_high: u128,
+    },
+
     /// Get the beneficiary address (view function)
     #[opcode(10010)]
     #[returns(String)]
@@ -196,30 +337,7 @@ impl BitcoinSale {
 
     /// Set the taproot address from three u128 parts
     pub fn set_taproot_address(&self, part1: u128, part2: u128, part3: u128) {
-        // Combine the three parts to form a 32-byte address
-        let mut address_bytes = Vec::with_capacity(32);
-        
-        // Extract the first 10 bytes from part1
-        let part1_bytes = part1.to_le_bytes();
-        address_bytes.extend_from_slice(&part1_bytes[0..10]);
-        
-        // Extract the next 10 bytes from part2
-        let part2_bytes = part2.to_le_bytes();
-        address_bytes.extend_from_slice(&part2_bytes[0..10]);
-        
-        // Extract the last 12 bytes from part3
-        let part3_bytes = part3.to_le_bytes();
-        address_bytes.extend_from_slice(&part3_bytes[0..12]);
-        
-        // Create a simple script that just pushes the address bytes
-        // This is a simplified approach - in a real implementation, 
-        // we would use proper taproot script creation
-        let mut script_bytes = Vec::new();
-        script_bytes.push(0x51); // OP_PUSHBYTES_32
-        script_bytes.extend_from_slice(&address_bytes);
-        
-        // Store the script
-        self.taproot_address_pointer().set(Arc::new(script_bytes));
+        self.taproot_address_pointer().set(Arc::new(taproot_script(part1, part2, part3)));
     }
 
     /// Get the taproot address as a string
@@ -233,6 +351,44 @@ impl BitcoinSale {
         to_address_str(script).unwrap_or_else(|| String::from("Invalid taproot address"))
     }
 
+    /// Get the pointer to the other beneficiaries
+    pub fn beneficiaries_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/beneficiaries")
+    }
+
+    /// Get the output script and share in basis points of each other beneficiary
+    pub fn beneficiaries(&self) -> Vec<(Vec<u8>, u128)> {
+        self.beneficiaries_pointer().get()
+            .chunks_exact(TAPROOT_SCRIPT_SIZE + 16)
+            .map(|chunk| (
+                chunk[..TAPROOT_SCRIPT_SIZE].to_vec(),
+                u128::from_le_bytes(chunk[TAPROOT_SCRIPT_SIZE..].try_into().unwrap()),
+            ))
+            .collect()
+    }
+
+    /// Set the other beneficiaries
+    pub fn set_beneficiaries(&self, beneficiaries: &[(Vec<u8>, u128)]) {
+        let mut bytes = Vec::with_capacity(beneficiaries.len() * (TAPROOT_SCRIPT_SIZE + 16));
+        for (script, bps) in beneficiaries {
+            bytes.extend_from_slice(script);
+            bytes.extend_from_slice(&bps.to_le_bytes());
+        }
+
+        self.beneficiaries_pointer().set(Arc::new(bytes));
+    }
+
+    /// Get every beneficiary's output script and share, starting with the taproot address,
+    /// which takes the shares left over and the rounding dust
+    pub fn beneficiary_shares(&self) -> Vec<(Vec<u8>, u128)> {
+        let others = self.beneficiaries();
+        let assigned: u128 = others.iter().map(|(_, bps)| *bps).sum();
+
+        let mut shares = vec![(self.taproot_address_script(), BPS_DENOMINATOR.saturating_sub(assigned))];
+        shares.extend(others);
+        shares
+    }
+
     /// Get the pointer to the price
     pub fn price_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/price")
@@ -285,8 +441,241 @@ impl BitcoinSale {
         Ok(())
     }
 
-    /// Get the terms of service
+    /// Get the pointer to the maximum number of orbitals per purchase
+    pub fn max_per_purchase_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/max-per-purchase")
+    }
+
+    /// Get the maximum number of orbitals per purchase (0 for no cap)
+    pub fn max_per_purchase(&self) -> u128 {
+        self.max_per_purchase_pointer().get_value::<u128>()
+    }
+
+    /// Set the maximum number of orbitals per purchase
+    pub fn set_max_per_purchase(&self, max: u128) {
+        self.max_per_purchase_pointer().set_value::<u128>(max);
+    }
+
+    /// Get the pointer to the maximum number of orbitals per buyer
+    pub fn max_per_buyer_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/max-per-buyer")
+    }
+
+    /// Get the maximum number of orbitals per buyer (0 for no cap)
+    pub fn max_per_buyer(&self) -> u128 {
+        self.max_per_buyer_pointer().get_value::<u128>()
+    }
+
+    /// Set the maximum number of orbitals per buyer
+    pub fn set_max_per_buyer(&self, max: u128) {
+        self.max_per_buyer_pointer().set_value::<u128>(max);
+    }
+
+    /// Get the pointer to the number of orbitals a buyer has purchased
+    pub fn buyer_minted_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/buyer-minted/").select(&key.to_vec())
+    }
+
+    /// Get the number of orbitals a buyer has purchased
+    pub fn buyer_minted(&self, key: &BuyerKey) -> u128 {
+        self.buyer_minted_pointer(key).get_value::<u128>()
+    }
+
+    /// Increment the number of orbitals a buyer has purchased
+    pub fn increment_buyer_minted(&self, key: &BuyerKey, count: u128) -> Result<()> {
+        let minted = overflow_error(self.buyer_minted(key).checked_add(count))
+            .map_err(|_| anyhow!("buyer counter overflow"))?;
+        self.buyer_minted_pointer(key).set_value::<u128>(minted);
+        Ok(())
+    }
+
+    /// Get the pointer to the referral cut
+    pub fn referral_bps_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/referral-bps")
+    }
+
+    /// Get the referrer's cut of each referred purchase in basis points (0 for no referrals)
+    pub fn referral_bps(&self) -> u128 {
+        self.referral_bps_pointer().get_value::<u128>()
+    }
+
+    /// Get the pointer to a referrer's count of referred purchases
+    pub fn referral_purchases_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/referral-purchases/").select(&key.to_vec())
+    }
+
+    /// Get the pointer to a referrer's count of referred orbitals
+    pub fn referral_orbitals_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/referral-orbitals/").select(&key.to_vec())
+    }
+
+    /// Get the pointer to the satoshis a referrer has been paid
+    pub fn referral_earned_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/referral-earned/").select(&key.to_vec())
+    }
+
+    /// Record a referred purchase and the referrer's cut of it
+    pub fn record_referral(&self, key: &BuyerKey, orbitals: u128, cut: u128) -> Result<()> {
+        let add = |pointer: StoragePointer, amount: u128, what: &str| -> Result<()> {
+            let mut pointer = pointer;
+            let value = overflow_error(pointer.get_value::<u128>().checked_add(amount))
+                .map_err(|_| anyhow!("referral {} overflow", what))?;
+            pointer.set_value::<u128>(value);
+            Ok(())
+        };
+
+        add(self.referral_purchases_pointer(key), 1, "purchase counter")?;
+        add(self.referral_orbitals_pointer(key), orbitals, "orbital counter")?;
+        add(self.referral_earned_pointer(key), cut, "earnings")
+    }
+
+    /// Get how many orbitals a buyer may take in one purchase under the mint caps
+    pub fn purchase_allowance(&self, buyer: Option<&BuyerKey>) -> u128 {
+        mint_allowance(self.max_per_purchase(), self.max_per_buyer(), buyer.map(|key| self.buyer_minted(key)))
+    }
+
+    /// Get the pointer to the Merkle allowlist root
+    pub fn allowlist_root_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/allowlist-root")
+    }
+
+    /// Get the Merkle allowlist root, if one is set
+    pub fn allowlist_root(&self) -> Option<MerkleHash> {
+        self.allowlist_root_pointer().get().as_ref().clone().try_into().ok()
+    }
+
+    /// Get the pointer to the block height the allowlist phase ends at
+    pub fn allowlist_end_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/allowlist-end")
+    }
+
+    /// Get the block height the allowlist phase ends at
+    pub fn allowlist_end(&self) -> u128 {
+        self.allowlist_end_pointer().get_value::<u128>()
+    }
+
+    /// Check if purchases currently need an allowlist proof
+    pub fn allowlist_active(&self) -> bool {
+        self.allowlist_root().is_some() && (self.height() as u128) < self.allowlist_end()
+    }
+
+    /// Get the pointer to the number of orbitals a buyer has taken from the allowlist
+    pub fn allowlist_minted_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/allowlist-minted/").select(&key.to_vec())
+    }
+
+    /// Get the number of orbitals a buyer has taken from the allowlist
+    pub fn allowlist_minted(&self, key: &BuyerKey) -> u128 {
+        self.allowlist_minted_pointer(key).get_value::<u128>()
+    }
+
+    /// Increment the number of orbitals a buyer has taken from the allowlist
+    pub fn increment_allowlist_minted(&self, key: &BuyerKey, count: u128) -> Result<()> {
+        let minted = overflow_error(self.allowlist_minted(key).checked_add(count))
+            .map_err(|_| anyhow!("allowlist counter overflow"))?;
+        self.allowlist_minted_pointer(key).set_value::<u128>(minted);
+        Ok(())
+    }
+
+    /// Get how many orbitals the allowlist lets a buyer take, checking their proof
+    ///
+    /// Returns `None` outside the allowlist phase
+    pub fn allowlist_allowance(&self, key: &BuyerKey, options: &PurchaseOptions) -> Result<Option<u128>> {
+        if !self.allowlist_active() {
+            return Ok(None);
+        }
+        let root = self.allowlist_root().ok_or_else(|| anyhow!("No allowlist root"))?;
+
+        let (max_quantity, proof) = options.merkle_proof.as_ref()
+            .ok_or_else(|| anyhow!("The allowlist phase requires an allowlist proof"))?;
+        let max_quantity = *max_quantity;
+        if !merkle::verify(&root, &merkle::leaf_hash(key, max_quantity), proof) {
+            return Err(anyhow!("Invalid allowlist proof"));
+        }
+
+        Ok(Some(max_quantity.saturating_sub(self.allowlist_minted(key))))
+    }
+
+    /// Get the pointer to the voucher signer
+    pub fn voucher_signer_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/voucher-signer")
+    }
+
+    /// Get the x-only public key that signs mint vouchers, if the sale accepts them
+    pub fn voucher_signer(&self) -> Option<[u8; 32]> {
+        self.voucher_signer_pointer().get().as_ref().clone().try_into().ok()
+    }
+
+    /// Get the pointer to the used flag for a voucher nonce
+    pub fn nonce_used_pointer(&self, nonce: u128) -> StoragePointer {
+        StoragePointer::from_keyword("/voucher-nonce/").select(&nonce.to_le_bytes().to_vec())
+    }
+
+    /// Check if a voucher nonce has been used
+    pub fn nonce_used(&self, nonce: u128) -> bool {
+        self.nonce_used_pointer(nonce).get_value::<u8>() == 1
+    }
+
+    /// Check a voucher for the buyer and spend its nonce
+    pub fn redeem_voucher(&self, voucher: &Voucher, buyer: &BuyerKey) -> Result<()> {
+        let context = self.context()?;
+        let signer = self.voucher_signer().ok_or_else(|| anyhow!("Sale does not accept vouchers"))?;
+
+        voucher.verify(&context.myself, buyer, &signer, self.height() as u128)?;
+        if self.nonce_used(voucher.nonce) {
+            return Err(anyhow!("Voucher nonce {} has already been used", voucher.nonce));
+        }
+        self.nonce_used_pointer(voucher.nonce).set_value::<u8>(1);
+        Ok(())
+    }
+
+    /// Get the pointer to the terms of service versions
+    pub fn terms_history_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/terms-history")
+    }
+
+    /// Get every published version of the terms of service, oldest first
+    pub fn terms_history(&self) -> Vec<TermsVersion> {
+        self.terms_history_pointer()
+            .get_list()
+            .into_iter()
+            .filter_map(|bytes| TermsVersion::from_bytes(&bytes).ok())
+            .collect()
+    }
+
+    /// Publish a version of the terms of service
+    ///
+    /// Returns the new version number (counting from 1)
+    pub fn publish_terms(&self, version: &TermsVersion) -> u128 {
+        self.terms_history_pointer().append(Arc::new(version.to_bytes()));
+        self.terms_history_pointer().length() as u128
+    }
+
+    /// Get the pointer to the flag requiring buyers to accept the terms of service
+    pub fn terms_required_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/terms-required")
+    }
+
+    /// Check if buyers must accept the terms of service with each purchase
+    pub fn terms_required(&self) -> bool {
+        self.terms_required_pointer().get_value::<u8>() == 1
+    }
+
+    /// Get the pointer to the terms version a buyer last accepted
+    pub fn terms_accepted_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/terms-accepted/").select(&key.to_vec())
+    }
+
+    /// Get the current terms of service: the text, or the URI of hash-committed terms
     pub fn terms_of_service(&self) -> String {
+        self.terms_history()
+            .pop()
+            .map(|version| version.body)
+            .unwrap_or_else(Self::default_terms_of_service)
+    }
+
+    /// Get the terms of service published when Initialize gives none
+    pub fn default_terms_of_service() -> String {
         "TERMS OF SERVICE AND SALE\n\n\
         By using this service and purchasing non-fungible units of this digital asset with Bitcoin, you agree to the following terms:\n\n\
         1. The digital assets provided are sold as-is without any warranty, express or implied.\n\
@@ -295,12 +684,53 @@ impl BitcoinSale {
         4. These digital assets are released without warranty to be used at your own risk.\n\
         5. The seller makes no guarantees regarding the value, utility, or functionality of these digital assets.\n\
         6. By completing a purchase, you acknowledge that you have read and agree to these terms.\n\
-        7. Bitcoin payments cannot be automatically refunded. If you send more Bitcoin than needed for your purchase, any excess amount will not be refunded through this system.\n\
-        8. The number of orbitals minted will be limited by the amount of Bitcoin sent and the available fuel for processing.\n\n\
+        7. Bitcoin payments cannot be automatically refunded. If you send more Bitcoin than needed for your purchase, any excess amount will not be refunded through this system. A purchase is rejected unless every beneficiary of the sale is paid its share.\n\
+        8. The number of orbitals minted will be limited by the amount of Bitcoin sent, the per-purchase and per-buyer caps and the available fuel for processing.\n\n\
         All sales are final. No refunds will be provided under any circumstances."
             .to_string()
     }
 
+    /// Get the pointer to the paused flag
+    pub fn paused_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/paused")
+    }
+
+    /// Check if purchases are paused
+    pub fn paused(&self) -> bool {
+        self.paused_pointer().get_value::<u8>() == 1
+    }
+
+    /// Check if the terms of service can change: only while purchases are paused, since they
+    /// open with the sale, so buyers never see them change under a purchase
+    pub fn terms_changeable(&self) -> bool {
+        self.paused()
+    }
+
+    /// Get the pointer to the configuration change log
+    pub fn config_log_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/config-log")
+    }
+
+    /// Get every configuration change made since initialization
+    pub fn config_log(&self) -> Vec<ConfigChange> {
+        self.config_log_pointer()
+            .get_list()
+            .into_iter()
+            .filter_map(|bytes| ConfigChange::from_bytes(&bytes).ok())
+            .collect()
+    }
+
+    /// Record a configuration change at the current block height
+    pub fn record_config_change(&self, field: u128, value: u128) {
+        self.record_config_detail(field, value, NO_DETAIL);
+    }
+
+    /// Record a configuration change with a detail at the current block height
+    pub fn record_config_detail(&self, field: u128, value: u128, detail: [u8; 32]) {
+        let change = ConfigChange { height: self.height() as u128, field, value, detail };
+        self.config_log_pointer().append(Arc::new(change.to_bytes()));
+    }
+
     /// Get the fuel amount for calls
     pub fn fuel(&self) -> u64 {
         // Default fuel value
@@ -331,22 +761,29 @@ impl BitcoinSale {
         }
     }
 
-    /// Compute the total output value sent to the taproot address
+    /// Compute the total output value sent to the beneficiaries
     fn compute_btc_output(&self, tx: &Transaction) -> u128 {
-        let taproot_script = self.taproot_address_script();
-        if taproot_script.is_empty() {
-            return 0;
-        }
-        
-        let total = tx.output.iter().fold(0, |r: u128, v: &TxOut| -> u128 {
-            if v.script_pubkey.as_bytes().to_vec() == taproot_script {
-                r + <u64 as Into<u128>>::into(v.value.to_sat())
-            } else {
-                r
+        self.beneficiary_shares()
+            .iter()
+            .map(|(script, _)| output_value(tx, script))
+            .sum()
+    }
+
+    /// Check that each beneficiary received at least its share of the cost of a purchase
+    fn check_beneficiary_outputs(&self, tx: &Transaction, cost: u128) -> Result<()> {
+        let shares = self.beneficiary_shares();
+        let bps: Vec<u128> = shares.iter().map(|(_, bps)| *bps).collect();
+        let parts = split_shares(cost, &bps, 0);
+
+        for ((script, _), part) in shares.iter().zip(parts) {
+            let received = output_value(tx, script);
+            if received < part {
+                let address = to_address_str(Script::from_bytes(script))
+                    .unwrap_or_else(|| hex_script(script));
+                return Err(anyhow!("Beneficiary {} received {} satoshis instead of {}", address, received, part));
             }
-        });
-        
-        total
+        }
+        Ok(())
     }
 
     /// Calculate the number of orbitals that can be purchased with the given BTC amount
@@ -368,12 +805,10 @@ impl BitcoinSale {
         taproot_part1: u128,
         taproot_part2: u128,
         taproot_part3: u128,
-        name_part1: u128,
-        name_part2: u128,
-        symbol: u128
+        orbital_template: u128
     ) -> Result<CallResponse> {
         let context = self.context()?;
-        let response = CallResponse::forward(&context.incoming_alkanes);
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
         // Configure the Bitcoin network
         configure_network();
@@ -381,16 +816,47 @@ impl BitcoinSale {
         // Prevent multiple initializations
         self.observe_initialization()?;
 
+        // Read the collection name and symbol that follow the six fixed arguments
+        let mut reader = InputReader::trailing(&context.inputs, 6);
+        let name = reader.next_string()?;
+        let symbol = reader.next_string()?;
+        let max_per_purchase = reader.next_u128_or(0);
+        let max_per_buyer = reader.next_u128_or(0);
+        let voucher_signer = match (reader.next_u128_or(0), reader.next_u128_or(0)) {
+            (0, 0) => None,
+            (low, high) => Some(merkle::hash_from_inputs(low, high)),
+        };
+        let primary = taproot_script(taproot_part1, taproot_part2, taproot_part3);
+        let beneficiaries = read_beneficiaries(&mut reader, &primary)?;
+        let referral_bps = reader.next_u128_or(0);
+        if referral_bps > BPS_DENOMINATOR {
+            return Err(anyhow!("Referral cut of {} bps is more than the whole payment", referral_bps));
+        }
+        let terms_required = reader.next_u128_or(0);
+        if terms_required > 1 {
+            return Err(anyhow!("Terms required flag must be 0 or 1"));
+        }
+        let terms = if reader.is_empty() {
+            TermsVersion::from_text(&Self::default_terms_of_service(), self.height() as u128)
+        } else {
+            TermsVersion::read(&mut reader, self.height() as u128)?
+        };
+
         // Get the current sequence number
         let sequence = context.myself.tx;
 
+        // Initialize opcode and orbital template followed by the name and symbol in the same encoding
+        let mut collection_inputs = vec![0, orbital_template];
+        collection_inputs.extend(encode_string(&name));
+        collection_inputs.extend(encode_string(&symbol));
+
         // Deploy the bitcoin-collection alkane using [6, BITCOIN_COLLECTION_TEMPLATE_ID]
         let collection_cellpack = Cellpack {
             target: AlkaneId {
                 block: 6,
                 tx: BITCOIN_COLLECTION_TEMPLATE_ID,
             },
-            inputs: vec![0, name_part1, name_part2, symbol], // Initialize opcode with name and symbol
+            inputs: collection_inputs,
         };
         
         let _collection_response = self.call(
@@ -411,6 +877,16 @@ impl BitcoinSale {
         // Set the taproot address from the three parts
         self.set_taproot_address(taproot_part1, taproot_part2, taproot_part3);
 
+        // Set the beneficiaries who share each payment with the taproot address
+        self.set_beneficiaries(&beneficiaries);
+
+        // Set the referrer's cut of referred purchases
+        self.referral_bps_pointer().set_value::<u128>(referral_bps);
+
+        // Publish the first version of the terms of service
+        self.terms_required_pointer().set_value::<u8>(terms_required as u8);
+        self.publish_terms(&terms);
+
         // Set the price
         self.set_price(price);
 
@@ -420,6 +896,134 @@ impl BitcoinSale {
         // Initialize the sold counter
         self.set_sold(0);
 
+        // Set the mint caps
+        self.set_max_per_purchase(max_per_purchase);
+        self.set_max_per_buyer(max_per_buyer);
+
+        // Set the key that signs mint vouchers
+        if let Some(signer) = voucher_signer {
+            XOnlyPublicKey::from_slice(&signer)
+                .map_err(|e| anyhow!("Invalid voucher signer: {}", e))?;
+            self.voucher_signer_pointer().set(Arc::new(signer.to_vec()));
+        }
+
+        // Mint the auth token to the deployer, who uses it to manage the allowlist.
+        // This happens after the collection is deployed so the collection keeps the next sequence.
+        response.alkanes.0.push(self.deploy_auth_token(1)?);
+
+        Ok(response)
+    }
+
+    /// Set the Merkle allowlist root and the end of the allowlist phase
+    fn set_allowlist(&self, root_low: u128, root_high: u128, end_height: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        let root = merkle::hash_from_inputs(root_low, root_high);
+        self.allowlist_root_pointer().set(Arc::new(root.to_vec()));
+        self.allowlist_end_pointer().set_value::<u128>(end_height);
+        self.record_config_detail(config_fields::MERKLE_ROOT, end_height, root);
+
+        Ok(response)
+    }
+
+    /// Change the price per orbital
+    fn update_price(&self, price: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        if price == 0 {
+            return Err(anyhow!("Price must be greater than zero"));
+        }
+        self.set_price(price);
+        self.record_config_change(config_fields::PRICE, price);
+
+        Ok(response)
+    }
+
+    /// Change the maximum number of orbitals that can be sold
+    fn update_limit(&self, limit: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        let new_limit = if limit == 0 { u128::MAX } else { limit };
+        if new_limit < self.sold() {
+            return Err(anyhow!("Limit {} is below the {} orbitals already sold", limit, self.sold()));
+        }
+        self.set_limit(limit);
+        self.record_config_change(config_fields::LIMIT, limit);
+
+        Ok(response)
+    }
+
+    /// Change the taproot address and optionally the other beneficiaries
+    fn update_beneficiary(&self, taproot_part1: u128, taproot_part2: u128, taproot_part3: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        let primary = taproot_script(taproot_part1, taproot_part2, taproot_part3);
+        let mut reader = InputReader::trailing(&context.inputs, 3);
+        if !reader.is_empty() {
+            let beneficiaries = read_beneficiaries(&mut reader, &primary)?;
+            self.set_beneficiaries(&beneficiaries);
+        } else {
+            // The new taproot address must not already be one of the other beneficiaries
+            check_distinct_beneficiaries(&primary, &self.beneficiaries())?;
+        }
+        self.set_taproot_address(taproot_part1, taproot_part2, taproot_part3);
+
+        // Log a hash of the whole set, taproot address included, so buyers can check who is paid
+        let shares = self.beneficiary_shares();
+        let mut encoded = Vec::with_capacity(shares.len() * (TAPROOT_SCRIPT_SIZE + 16));
+        for (script, bps) in &shares {
+            encoded.extend_from_slice(script);
+            encoded.extend_from_slice(&bps.to_le_bytes());
+        }
+        self.record_config_detail(config_fields::BENEFICIARIES, shares.len() as u128, digest_detail(&encoded));
+
+        Ok(response)
+    }
+
+    /// Publish a new version of the terms of service
+    fn update_terms(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        if !self.terms_changeable() {
+            return Err(anyhow!("Terms of service can only change while the sale is paused"));
+        }
+
+        let mut reader = InputReader::trailing(&context.inputs, 0);
+        let terms = TermsVersion::read(&mut reader, self.height() as u128)?;
+        let version = self.publish_terms(&terms);
+        self.record_config_change(config_fields::TERMS, version);
+
+        Ok(response)
+    }
+
+    /// Pause or resume purchases
+    fn set_paused(&self, paused: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        if paused > 1 {
+            return Err(anyhow!("Paused flag must be 0 or 1"));
+        }
+        self.paused_pointer().set_value::<u8>(paused as u8);
+        self.record_config_change(config_fields::PAUSED, paused);
+
         Ok(response)
     }
 
@@ -428,6 +1032,11 @@ impl BitcoinSale {
         let context = self.context()?;
         let mut response = CallResponse::default();
 
+        // The admin can pause purchases
+        if self.paused() {
+            return Err(anyhow!("Sale is paused"));
+        }
+
         // Configure the Bitcoin network
         configure_network();
 
@@ -444,16 +1053,68 @@ impl BitcoinSale {
         // Check if the transaction has already been processed
         self.observe_transaction(&tx)?;
 
-        // Compute the amount of BTC sent to the taproot address
-        let btc_amount = self.compute_btc_output(&tx);
+        // Read the optional proofs that follow the opcode
+        let options = PurchaseOptions::read(&mut InputReader::trailing(&context.inputs, 0))?;
+        if options.referrer.is_some() {
+            return Err(anyhow!("The Bitcoin sale reads the referrer from an OP_RETURN output"));
+        }
+
+        // Check the buyer accepted the current terms of service when they passed a hash or must
+        let accepted = accepted_version(&self.terms_history(), options.terms.as_ref(), self.terms_required())?;
+
+        // A referrer named in an OP_RETURN output is paid their cut directly in the transaction
+        let referrer = if self.referral_bps() != 0 { referrer_script(&tx) } else { None };
+
+        // Identify the buyer by the output the orbitals are sent to, only when the sale keeps
+        // per-buyer counts, checks a voucher or allowlist proof, pays a referrer or records
+        // terms acceptance
+        let buyer = if self.max_per_buyer() != 0
+            || options.voucher.is_some()
+            || self.allowlist_active()
+            || referrer.is_some()
+            || accepted.is_some()
+        {
+            Some(buyer_key(&pointer_script(&tx, context.vout)?))
+        } else {
+            None
+        };
+
+        if let Some(script) = &referrer {
+            if Some(buyer_key(script)) == buyer {
+                return Err(anyhow!("Buyers cannot refer themselves"));
+            }
+            if self.beneficiary_shares().iter().any(|(beneficiary, _)| beneficiary == script.as_bytes()) {
+                return Err(anyhow!("A beneficiary of the sale cannot be a referrer"));
+            }
+        }
+        let referrer_paid = referrer.as_ref().map_or(0, |script| output_value(&tx, script.as_bytes()));
+
+        // Compute the amount of BTC sent to the beneficiaries and the referrer
+        let btc_amount = self.compute_btc_output(&tx).saturating_add(referrer_paid);
         
-        // Check if payment was provided
-        if btc_amount == 0 {
+        // Check if payment was provided, unless a free voucher pays for the orbitals
+        let free_voucher = options.voucher.as_ref().is_some_and(|voucher| voucher.price == 0);
+        if btc_amount == 0 && !free_voucher {
             return Err(anyhow!("No BTC payment sent to the specified taproot address"));
         }
 
+        // Record the terms version the buyer accepted
+        if let (Some(version), Some(key)) = (accepted, &buyer) {
+            self.terms_accepted_pointer(key).set_value::<u128>(version);
+        }
+
+        // A voucher is signed for this buyer, can only be used once and sets the price
+        let voucher = options.voucher.as_ref();
+        if let (Some(voucher), Some(key)) = (voucher, &buyer) {
+            self.redeem_voucher(voucher, key)?;
+        }
+
         // Calculate how many orbitals can be purchased
-        let purchase_count = self.calculate_purchase_count(btc_amount);
+        let purchase_count = match voucher {
+            Some(voucher) if voucher.price == 0 => u128::MAX,
+            Some(voucher) => btc_amount / voucher.price,
+            None => self.calculate_purchase_count(btc_amount),
+        };
         
         // Check if at least one orbital can be purchased
         if purchase_count == 0 {
@@ -461,7 +1122,7 @@ impl BitcoinSale {
         }
 
         // Check if the purchase would exceed the limit
-        let new_sold = self.sold() + purchase_count;
+        let new_sold = self.sold().saturating_add(purchase_count);
         let actual_purchase_count = if new_sold > self.limit() {
             self.limit() - self.sold()
         } else {
@@ -472,6 +1133,45 @@ impl BitcoinSale {
             return Err(anyhow!("Sale limit reached"));
         }
 
+        // Hold the buyer to the mint caps and the voucher quantity
+        let mut allowance = self.purchase_allowance(buyer.as_ref());
+        if allowance == 0 {
+            return Err(anyhow!("Buyer has reached the limit of {} orbitals", self.max_per_buyer()));
+        }
+        if let Some(voucher) = voucher {
+            if voucher.quantity == 0 {
+                return Err(anyhow!("Voucher does not allow any orbitals"));
+            }
+            allowance = allowance.min(voucher.quantity);
+        }
+
+        // During the allowlist phase the buyer must prove their leaf and stay within its quantity,
+        // unless the admin signed them a voucher
+        let allowlist_allowance = match (voucher, &buyer) {
+            (None, Some(key)) => self.allowlist_allowance(key, &options)?,
+            _ => None,
+        };
+        if let Some(listed) = allowlist_allowance {
+            if listed == 0 {
+                return Err(anyhow!("Buyer has used their allowlist quantity"));
+            }
+            allowance = allowance.min(listed);
+        }
+        let actual_purchase_count = actual_purchase_count.min(allowance);
+
+        // The referrer must be paid their cut of what the orbitals cost
+        // and every beneficiary its share of the rest
+        let unit_price = voucher.map_or(self.price(), |voucher| voucher.price);
+        let cost = unit_price.saturating_mul(actual_purchase_count);
+        let referral_cut = match &referrer {
+            Some(_) => share_of(cost, self.referral_bps()),
+            None => 0,
+        };
+        if referrer_paid < referral_cut {
+            return Err(anyhow!("Referrer received {} satoshis instead of {}", referrer_paid, referral_cut));
+        }
+        self.check_beneficiary_outputs(&tx, cost - referral_cut)?;
+
         // Get the collection alkane ID
         let collection_id = self.collection_alkane_id()?;
 
@@ -525,6 +1225,15 @@ impl BitcoinSale {
 
         // Update the sold counter with the actual number minted
         self.increment_sold(minted_count)?;
+        if let Some(key) = &buyer {
+            self.increment_buyer_minted(key, minted_count)?;
+            if allowlist_allowance.is_some() {
+                self.increment_allowlist_minted(key, minted_count)?;
+            }
+        }
+        if let Some(script) = &referrer {
+            self.record_referral(&buyer_key(script), minted_count, referrer_paid)?;
+        }
 
         // Add the purchased orbitals to the response
         response.alkanes.0.extend(purchased_orbitals);
@@ -597,7 +1306,7 @@ impl BitcoinSale {
         Ok(response)
     }
 
-    /// Get the terms of service
+    /// Get the current terms of service
     fn get_terms_of_service(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
@@ -617,6 +1326,135 @@ impl BitcoinSale {
         Ok(response)
     }
     
+    /// Get how many orbitals a buyer can still purchase in one transaction
+    fn get_allowance(&self, key_low: u128, key_high: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let key = buyer_key_from_inputs(key_low, key_high);
+        let remaining = self.limit().saturating_sub(self.sold());
+        response.data = self.purchase_allowance(Some(&key)).min(remaining).to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get the Merkle allowlist root and the end of the allowlist phase
+    fn get_allowlist(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let mut bytes = self.allowlist_root().unwrap_or([0u8; 32]).to_vec();
+        bytes.extend_from_slice(&self.allowlist_end().to_le_bytes());
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get the x-only public key that signs mint vouchers
+    fn get_voucher_signer(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.voucher_signer()
+            .ok_or_else(|| anyhow!("Sale does not accept vouchers"))?
+            .to_vec();
+
+        Ok(response)
+    }
+
+    /// Check if a voucher nonce has been used
+    fn get_nonce_used(&self, nonce: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = (self.nonce_used(nonce) as u128).to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get every configuration change
+    fn get_config_log(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let log = self.config_log();
+        let mut bytes = (log.len() as u128).to_le_bytes().to_vec();
+        for change in &log {
+            bytes.extend(change.to_bytes());
+        }
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Check if purchases are paused
+    fn get_paused(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = (self.paused() as u128).to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get every version of the terms of service
+    fn get_terms_history(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = encode_history(&self.terms_history());
+
+        Ok(response)
+    }
+
+    /// Get the terms version a buyer last accepted
+    fn get_terms_accepted(&self, key_low: u128, key_high: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let key = buyer_key_from_inputs(key_low, key_high);
+        response.data = self.terms_accepted_pointer(&key).get_value::<u128>().to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get a referrer's counts and earnings
+    fn get_referral_stats(&self, key_low: u128, key_high: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let key = buyer_key_from_inputs(key_low, key_high);
+        let mut bytes = Vec::with_capacity(64);
+        bytes.extend_from_slice(&self.referral_bps().to_le_bytes());
+        bytes.extend_from_slice(&self.referral_purchases_pointer(&key).get_value::<u128>().to_le_bytes());
+        bytes.extend_from_slice(&self.referral_orbitals_pointer(&key).get_value::<u128>().to_le_bytes());
+        bytes.extend_from_slice(&self.referral_earned_pointer(&key).get_value::<u128>().to_le_bytes());
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get each beneficiary's address and share
+    fn get_beneficiaries(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Configure the network to ensure proper address formatting
+        configure_network();
+
+        let lines: Vec<String> = self.beneficiary_shares()
+            .iter()
+            .map(|(script, bps)| {
+                let address = to_address_str(Script::from_bytes(script))
+                    .unwrap_or_else(|| hex_script(script));
+                format!("{} {}", address, bps)
+            })
+            .collect();
+        response.data = lines.join("\n").into_bytes();
+
+        Ok(response)
+    }
+
     /// Get the beneficiary address (view function)
     fn get_beneficiary(&self) -> Result<CallResponse> {
         let _context = self.context()?;
@@ -632,6 +1470,89 @@ impl BitcoinSale {
     }
 }
 
+/// Size of a pay-to-taproot output script: OP_1, a 32-byte push and the key
+const TAPROOT_SCRIPT_SIZE: usize = 34;
+
+/// Build a pay-to-taproot output script from an x-only key split into three u128 parts
+/// (the first 10, next 10 and last 12 bytes of the key, each little-endian)
+pub fn taproot_script(part1: u128, part2: u128, part3: u128) -> Vec<u8> {
+    let mut script = Vec::with_capacity(TAPROOT_SCRIPT_SIZE);
+    script.push(0x51); // OP_1 (segwit version 1)
+    script.push(0x20); // OP_PUSHBYTES_32
+    script.extend_from_slice(&part1.to_le_bytes()[0..10]);
+    script.extend_from_slice(&part2.to_le_bytes()[0..10]);
+    script.extend_from_slice(&part3.to_le_bytes()[0..12]);
+    script
+}
+
+/// Get the total value of the transaction outputs paying to a script
+fn output_value(tx: &Transaction, script: &[u8]) -> u128 {
+    if script.is_empty() {
+        return 0;
+    }
+
+    tx.output.iter().fold(0, |r: u128, v: &TxOut| -> u128 {
+        if v.script_pubkey.as_bytes() == script {
+            r + <u64 as Into<u128>>::into(v.value.to_sat())
+        } else {
+            r
+        }
+    })
+}
+
+/// Format a script as hex when it has no address on the configured network
+fn hex_script(script: &[u8]) -> String {
+    script.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Read the other beneficiaries from the Initialize or UpdateBeneficiary inputs
+///
+/// Their shares must leave the taproot address (`primary`) with the rest, which may be
+/// nothing, and none of them may repeat a script
+fn read_beneficiaries(reader: &mut InputReader, primary: &[u8]) -> Result<Vec<(Vec<u8>, u128)>> {
+    let count = usize::try_from(reader.next_u128_or(0)).map_err(|_| anyhow!("too many beneficiaries"))?;
+    if count >= MAX_BENEFICIARIES {
+        return Err(anyhow!("At most {} other beneficiaries are supported", MAX_BENEFICIARIES - 1));
+    }
+
+    let mut beneficiaries = Vec::with_capacity(count);
+    for _ in 0..count {
+        let script = taproot_script(reader.next_u128()?, reader.next_u128()?, reader.next_u128()?);
+        beneficiaries.push((script, reader.next_u128()?));
+    }
+    check_distinct_beneficiaries(primary, &beneficiaries)?;
+
+    // The taproot address is the first beneficiary and takes the rounding dust
+    let assigned = beneficiaries
+        .iter()
+        .try_fold(0u128, |total, (_, bps)| total.checked_add(*bps))
+        .ok_or_else(|| anyhow!("Beneficiary shares overflow"))?;
+    if assigned > BPS_DENOMINATOR {
+        return Err(anyhow!("Beneficiary shares add up to {} bps, more than {}", assigned, BPS_DENOMINATOR));
+    }
+    let mut shares = vec![BPS_DENOMINATOR - assigned];
+    shares.extend(beneficiaries.iter().map(|(_, bps)| *bps));
+    validate_shares(&shares, 0)?;
+
+    Ok(beneficiaries)
+}
+
+/// Check that no output script is named twice among the beneficiaries, counting the
+/// taproot address, so each share is checked against its own output
+fn check_distinct_beneficiaries(primary: &[u8], others: &[(Vec<u8>, u128)]) -> Result<()> {
+    for (index, (script, _)) in others.iter().enumerate() {
+        if script.as_slice() == primary {
+            return Err(anyhow!("Beneficiary {} is the taproot address", index));
+        }
+        if others[..index].iter().any(|(earlier, _)| earlier == script) {
+            return Err(anyhow!("Beneficiary {} is listed more than once", index));
+        }
+    }
+    Ok(())
+}
+
+impl AuthenticatedResponder for BitcoinSale {}
+
 impl AlkaneResponder for BitcoinSale {
     fn execute(&self) -> Result<CallResponse> {
         // This method should not be called directly when using MessageDispatch
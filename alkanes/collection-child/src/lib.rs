@@ -93,6 +93,30 @@ impl OrbitalInstance {
         // Use the implementation from the Orbital trait
         Orbital::get_data(self)
     }
+
+    /// Get the size of the orbital's data
+    fn get_data_size(&self) -> Result<CallResponse> {
+        // Use the implementation from the Orbital trait
+        Orbital::get_data_size(self)
+    }
+
+    /// Get a range of the orbital's data
+    fn get_data_range(&self, offset: u128, length: u128) -> Result<CallResponse> {
+        // Use the implementation from the Orbital trait
+        Orbital::get_data_range(self, offset, length)
+    }
+
+    /// Get the MIME type of the orbital's data
+    fn get_content_type(&self) -> Result<CallResponse> {
+        // Use the implementation from the Orbital trait
+        Orbital::get_content_type(self)
+    }
+
+    /// Get the SHA-256 hash of the orbital's data
+    fn get_content_hash(&self) -> Result<CallResponse> {
+        // Use the implementation from the Orbital trait
+        Orbital::get_content_hash(self)
+    }
 }
 
 
@@ -124,6 +148,31 @@ enum OrbitalMessage {
     #[opcode(1000)]
     #[returns(Vec<u8>)]
     GetData,
+
+    /// Get the size of the orbital's data in bytes
+    #[opcode(1001)]
+    #[returns(u128)]
+    GetDataSize,
+
+    /// Get the bytes of the orbital's data in [offset, offset + length)
+    #[opcode(1002)]
+    #[returns(Vec<u8>)]
+    GetDataRange {
+        /// Offset of the first byte
+        offset: u128,
+        /// Number of bytes to return, clamped to the end of the data
+        length: u128,
+    },
+
+    /// Get the MIME type of the orbital's data
+    #[opcode(1003)]
+    #[returns(String)]
+    GetContentType,
+
+    /// Get the SHA-256 hash of the orbital's data
+    #[opcode(1004)]
+    #[returns(Vec<u8>)]
+    GetContentHash,
 }
 
 impl AlkaneResponder for OrbitalInstance {
@@ -5,7 +5,7 @@ use alkanes_runtime::{
     println,
     stdio::{stdout, Write},
 };
-use alkanes_runtime::{runtime::AlkaneResponder, storage::StoragePointer, token::Token};
+use alkanes_runtime::{auth::AuthenticatedResponder, runtime::AlkaneResponder, storage::StoragePointer, token::Token};
 use alkanes_support::response::CallResponse;
 use anyhow::{anyhow, Result};
 use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
@@ -16,61 +16,86 @@ use alkanes_support::parcel::AlkaneTransferParcel;
 use alkanes_support::cellpack::Cellpack;
 use std::sync::Arc;
 use alkanes_runtime::imports::__call;
+use orbitals_support::codec::InputReader;
 
-/// Orbital template ID - this is the template used for creating orbital instances
+// Minter allowlist bookkeeping
+pub mod minters;
+
+// Orbital registry index and paging arithmetic
+pub mod registry;
+pub use registry::MAX_PAGE_SIZE;
+
+/// Default orbital template ID - used when a collection is initialized without one
 pub const ORBITAL_TEMPLATE_ID: u128 = 0xe0e2;
 
 /// Collection alkane that acts as a factory for orbital instances
 #[derive(Default)]
 pub struct Collection(());
 
-/// TokenName struct to hold two u128 values for the name
-#[derive(Default, Clone, Copy)]
-pub struct TokenName {
-    pub part1: u128,
-    pub part2: u128,
-}
-
-impl From<TokenName> for String {
-    fn from(name: TokenName) -> Self {
-        // Trim both parts and concatenate them
-        format!("{}{}", trim(name.part1), trim(name.part2))
-    }
-}
-
-impl TokenName {
-    pub fn new(part1: u128, part2: u128) -> Self {
-        Self { part1, part2 }
-    }
-}
-
-/// Trims a u128 value to a String by removing trailing zeros
-pub fn trim(v: u128) -> String {
-    String::from_utf8(
-        v.to_le_bytes()
-            .into_iter()
-            .fold(Vec::<u8>::new(), |mut r, v| {
-                if v != 0 {
-                    r.push(v)
-                }
-                r
-            }),
-    )
-    .unwrap_or_default()
-}
-
 /// Message enum for opcode-based dispatch
 #[derive(MessageDispatch)]
 enum CollectionMessage {
     /// Initialize the collection
+    /// Followed by the name and symbol as length-prefixed strings
     #[opcode(0)]
     Initialize {
-        /// Name part 1
-        name_part1: u128,
-        /// Name part 2
-        name_part2: u128,
-        /// Symbol
-        symbol: u128,
+        /// Orbital template ID (0 for the default template)
+        orbital_template: u128,
+    },
+
+    /// Add an alkane to the minter allowlist (requires the auth token)
+    #[opcode(10)]
+    AddMinter {
+        /// Minter alkane block
+        minter_block: u128,
+        /// Minter alkane tx
+        minter_tx: u128,
+    },
+
+    /// Remove an alkane from the minter allowlist (requires the auth token)
+    #[opcode(11)]
+    RemoveMinter {
+        /// Minter alkane block
+        minter_block: u128,
+        /// Minter alkane tx
+        minter_tx: u128,
+    },
+
+    /// Set the collection description (requires the auth token)
+    /// Followed by the description as a length-prefixed string
+    #[opcode(20)]
+    SetDescription,
+
+    /// Set the collection external URL (requires the auth token)
+    /// Followed by the URL as a length-prefixed string
+    #[opcode(21)]
+    SetExternalUrl,
+
+    /// Set the collection license (requires the auth token)
+    /// Followed by the license as a length-prefixed string
+    #[opcode(22)]
+    SetLicense,
+
+    /// Set the collection creator (requires the auth token)
+    /// Followed by the creator as a length-prefixed string
+    #[opcode(23)]
+    SetCreator,
+
+    /// Set the collection attributes (requires the auth token)
+    /// Followed by a JSON document as a length-prefixed string
+    #[opcode(24)]
+    SetAttributes,
+
+    /// Permanently freeze the collection (requires the auth token)
+    /// Every admin mutation fails once the collection is frozen
+    #[opcode(30)]
+    Freeze,
+
+    /// Switch the orbital template used for future mints (requires the auth token)
+    #[opcode(40)]
+    SetOrbitalTemplate {
+        /// Orbital template ID
+        orbital_template: u128,
     },
 
     /// Create a new orbital instance (only callable by authorized alkanes)
@@ -97,10 +122,84 @@ enum CollectionMessage {
     #[returns(u128)]
     GetOrbitalCount,
 
+    /// Get the allowlisted minters as consecutive 32-byte AlkaneIds
+    #[opcode(103)]
+    #[returns(Vec<u8>)]
+    GetMinters,
+
+    /// Get the AlkaneId of the orbital at an index
+    #[opcode(104)]
+    #[returns(Vec<u8>)]
+    GetOrbitalAt {
+        /// Index in the collection (0-based)
+        index: u128,
+    },
+
+    /// Get a page of orbital AlkaneIds as consecutive 32-byte values
+    #[opcode(105)]
+    #[returns(Vec<u8>)]
+    GetOrbitals {
+        /// Index of the first orbital in the page (0-based)
+        offset: u128,
+        /// Maximum number of orbitals to return (capped at MAX_PAGE_SIZE)
+        limit: u128,
+    },
+
+    /// Check whether an alkane is an orbital of this collection
+    /// Returns a u128 membership flag followed by the u128 index
+    #[opcode(106)]
+    #[returns(Vec<u8>)]
+    GetOrbitalIndex {
+        /// Orbital alkane block
+        orbital_block: u128,
+        /// Orbital alkane tx
+        orbital_tx: u128,
+    },
+
+    /// Get the collection metadata as a JSON document
+    #[opcode(107)]
+    #[returns(String)]
+    GetMetadata,
+
+    /// Get the freeze status as a u128 frozen flag followed by the u128 freeze height
+    #[opcode(108)]
+    #[returns(Vec<u8>)]
+    GetFreezeStatus,
+
+    /// Get the orbital template ID used for new mints
+    #[opcode(109)]
+    #[returns(u128)]
+    GetOrbitalTemplate,
+
     /// Get the data of the collection with optional transform
     #[opcode(1000)]
     #[returns(Vec<u8>)]
     GetData,
+
+    /// Get the size of the collection data in bytes
+    #[opcode(1001)]
+    #[returns(u128)]
+    GetDataSize,
+
+    /// Get the bytes of the collection data in [offset, offset + length)
+    #[opcode(1002)]
+    #[returns(Vec<u8>)]
+    GetDataRange {
+        /// Offset of the first byte
+        offset: u128,
+        /// Number of bytes to return, clamped to the end of the data
+        length: u128,
+    },
+
+    /// Get the MIME type of the collection data
+    #[opcode(1003)]
+    #[returns(String)]
+    GetContentType,
+
+    /// Get the SHA-256 hash of the collection data
+    #[opcode(1004)]
+    #[returns(Vec<u8>)]
+    GetContentHash,
 }
 
 impl Token for Collection {
@@ -147,15 +246,52 @@ impl Collection {
     }
 
     /// Set the name and symbol
-    pub fn set_name_and_symbol(&self, name: TokenName, symbol: u128) {
-        let name_string: String = name.into();
-        self.set_name(&name_string);
-        self.set_string_field(self.symbol_pointer(), symbol);
+    pub fn set_name_and_symbol(&self, name: &str, symbol: &str) {
+        self.set_name(name);
+        self.set_symbol(symbol);
+    }
+
+    /// Get the pointer to a metadata field
+    pub fn metadata_pointer(&self, field: &str) -> StoragePointer {
+        StoragePointer::from_keyword("/metadata/").keyword(field)
+    }
+
+    /// Get a metadata field, or an empty string if it has not been set
+    pub fn metadata_field(&self, field: &str) -> String {
+        String::from_utf8_lossy(self.metadata_pointer(field).get().as_ref()).to_string()
+    }
+
+    /// Set a metadata field
+    pub fn set_metadata_field(&self, field: &str, value: &str) {
+        self.metadata_pointer(field).set(Arc::new(value.as_bytes().to_vec()));
+    }
+
+    /// Get a metadata field as a JSON string, or null if it has not been set
+    fn metadata_value(&self, field: &str) -> serde_json::Value {
+        match self.metadata_field(field) {
+            value if value.is_empty() => serde_json::Value::Null,
+            value => serde_json::Value::String(value),
+        }
     }
 
-    /// Set a string field in storage
-    fn set_string_field(&self, mut pointer: StoragePointer, v: u128) {
-        pointer.set(Arc::new(trim(v).as_bytes().to_vec()));
+    /// Build the collection metadata JSON document
+    pub fn metadata(&self) -> Result<serde_json::Value> {
+        // Attributes are validated when set, so they can be embedded as JSON
+        let attributes = match self.metadata_field("attributes") {
+            value if value.is_empty() => serde_json::Value::Null,
+            value => serde_json::from_str(&value)
+                .map_err(|e| anyhow!("stored attributes are not valid JSON: {}", e))?,
+        };
+
+        Ok(serde_json::json!({
+            "name": self.name(),
+            "symbol": self.symbol(),
+            "description": self.metadata_value("description"),
+            "external_url": self.metadata_value("external-url"),
+            "license": self.metadata_value("license"),
+            "creator": self.metadata_value("creator"),
+            "attributes": attributes,
+        }))
     }
 
     /// Get the total supply
@@ -170,10 +306,22 @@ impl Collection {
         1000000
     }
 
+    /// Get the pointer to the orbital template ID
+    pub fn orbital_template_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/orbital-template")
+    }
+
     /// Get the orbital template ID
     pub fn orbital_template(&self) -> u128 {
-        // Return the constant template ID
-        ORBITAL_TEMPLATE_ID
+        match self.orbital_template_pointer().get_value::<u128>() {
+            0 => ORBITAL_TEMPLATE_ID,
+            template => template,
+        }
+    }
+
+    /// Set the orbital template ID, where 0 selects the default template
+    pub fn set_orbital_template_id(&self, template: u128) {
+        self.orbital_template_pointer().set_value::<u128>(template);
     }
 
     /// Get the pointer to the container sequence
@@ -220,6 +368,11 @@ impl Collection {
         let bytes_vec = new_count.to_le_bytes().to_vec();
         let mut instance_pointer = self.instances_pointer().select(&bytes_vec);
         instance_pointer.set(Arc::new(bytes));
+
+        // Record the reverse mapping from the instance ID to its 0-based index
+        let index = registry::index_of(new_count)
+            .ok_or_else(|| anyhow!("invalid instance sequence"))?;
+        self.instance_index_pointer(instance_id).set_value::<u128>(index);
         
         // Update the count
         self.set_instances_count(new_count);
@@ -227,6 +380,30 @@ impl Collection {
         Ok(new_count)
     }
 
+    /// Get the pointer to the reverse lookup entry for an instance ID
+    pub fn instance_index_pointer(&self, instance_id: &AlkaneId) -> StoragePointer {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&instance_id.block.to_le_bytes());
+        bytes.extend_from_slice(&instance_id.tx.to_le_bytes());
+        StoragePointer::from_keyword("/instance-index/").select(&bytes)
+    }
+
+    /// Get the 0-based index of an instance, or None if it is not part of the collection
+    pub fn instance_index(&self, instance_id: &AlkaneId) -> Option<u128> {
+        let pointer = self.instance_index_pointer(instance_id);
+        if pointer.get().len() == 0 {
+            None
+        } else {
+            Some(pointer.get_value::<u128>())
+        }
+    }
+
+    /// Get an instance by its 0-based index
+    pub fn orbital_at(&self, index: u128) -> Option<AlkaneId> {
+        // The registry is keyed by 1-based sequence numbers
+        self.get_instance(registry::sequence_of(index)?)
+    }
+
     /// Get an instance by sequence number
     pub fn get_instance(&self, sequence: u128) -> Option<AlkaneId> {
         let bytes_vec = sequence.to_le_bytes().to_vec();
@@ -243,14 +420,59 @@ impl Collection {
         }
     }
 
+    /// Get the pointer to the minter allowlist entry for an alkane ID
+    pub fn minter_pointer(&self, alkane_id: &AlkaneId) -> StoragePointer {
+        minters::minter_pointer(alkane_id)
+    }
+
+    /// Get the pointer to the list of every alkane ID ever added as a minter
+    pub fn minter_list_pointer(&self) -> StoragePointer {
+        minters::minter_list_pointer()
+    }
+
+    /// Add an alkane ID to the minter allowlist
+    pub fn allow_minter(&self, alkane_id: &AlkaneId) {
+        minters::allow_minter::<StoragePointer>(alkane_id);
+    }
+
+    /// Remove an alkane ID from the minter allowlist
+    pub fn disallow_minter(&self, alkane_id: &AlkaneId) {
+        minters::disallow_minter::<StoragePointer>(alkane_id);
+    }
+
+    /// Get the alkane IDs currently on the minter allowlist
+    pub fn minters(&self) -> Vec<AlkaneId> {
+        minters::minters::<StoragePointer>()
+    }
+
     /// Check if an alkane ID is authorized to create orbitals
-    pub fn is_authorized(&self, _alkane_id: &AlkaneId) -> bool {
-        // In a real implementation, we would check against a list of authorized alkanes
-        // For now, we'll just return true for simplicity
-        // TODO: Implement proper authorization
-        true
+    pub fn is_authorized(&self, alkane_id: &AlkaneId) -> bool {
+        minters::is_minter::<StoragePointer>(alkane_id)
+    }
+
+
+    /// Get the pointer to the block height the collection was frozen at
+    pub fn frozen_height_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/frozen-height")
     }
 
+    /// Get the block height the collection was frozen at, or None if it is not frozen
+    pub fn frozen_height(&self) -> Option<u64> {
+        let pointer = self.frozen_height_pointer();
+        if pointer.get().len() == 0 {
+            None
+        } else {
+            Some(pointer.get_value::<u64>())
+        }
+    }
+
+    /// Fail if the collection has been frozen
+    pub fn observe_unfrozen(&self) -> Result<()> {
+        match self.frozen_height() {
+            Some(height) => Err(anyhow!("collection was frozen at height {}", height)),
+            None => Ok(()),
+        }
+    }
 
     /// Observe initialization to prevent multiple initializations
     pub fn observe_initialization(&self) -> Result<()> {
@@ -264,16 +486,20 @@ impl Collection {
     }
 
     /// Initialize the collection
-    fn initialize(&self, name_part1: u128, name_part2: u128, symbol: u128) -> Result<CallResponse> {
+    fn initialize(&self, orbital_template: u128) -> Result<CallResponse> {
         let context = self.context()?;
-        let response = CallResponse::forward(&context.incoming_alkanes);
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
         // Prevent multiple initializations
         self.observe_initialization()?;
 
-        // Create TokenName from the two parts
-        let name = TokenName::new(name_part1, name_part2);
-        self.set_name_and_symbol(name, symbol);
+        // Read the name and symbol that follow the orbital template
+        let mut reader = InputReader::trailing(&context.inputs, 1);
+        let name = reader.next_string()?;
+        let symbol = reader.next_string()?;
+        self.set_name_and_symbol(&name, &symbol);
+
+        self.set_orbital_template_id(orbital_template);
 
         // Initialize the instances count
         self.set_instances_count(0);
@@ -303,6 +529,119 @@ impl Collection {
         // Store the container sequence number
         self.set_container_sequence(container_sequence + 1);
 
+        // Deploy the auth token after the container so the container keeps the next sequence
+        // The deployer uses it to manage the minter allowlist
+        response.alkanes.0.push(self.deploy_auth_token(1)?);
+
+        Ok(response)
+    }
+
+    /// Add an alkane to the minter allowlist
+    fn add_minter(&self, minter_block: u128, minter_tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        self.observe_unfrozen()?;
+
+        self.allow_minter(&AlkaneId {
+            block: minter_block,
+            tx: minter_tx,
+        });
+
+        Ok(response)
+    }
+
+    /// Remove an alkane from the minter allowlist
+    fn remove_minter(&self, minter_block: u128, minter_tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        self.observe_unfrozen()?;
+
+        self.disallow_minter(&AlkaneId {
+            block: minter_block,
+            tx: minter_tx,
+        });
+
+        Ok(response)
+    }
+
+    /// Replace a metadata field with the string that follows the opcode
+    fn update_metadata_field(&self, field: &str) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        self.observe_unfrozen()?;
+
+        let value = InputReader::trailing(&context.inputs, 0).next_string()?;
+        self.set_metadata_field(field, &value);
+
+        Ok(response)
+    }
+
+    /// Set the collection description
+    fn set_description(&self) -> Result<CallResponse> {
+        self.update_metadata_field("description")
+    }
+
+    /// Set the collection external URL
+    fn set_external_url(&self) -> Result<CallResponse> {
+        self.update_metadata_field("external-url")
+    }
+
+    /// Set the collection license
+    fn set_license(&self) -> Result<CallResponse> {
+        self.update_metadata_field("license")
+    }
+
+    /// Set the collection creator
+    fn set_creator(&self) -> Result<CallResponse> {
+        self.update_metadata_field("creator")
+    }
+
+    /// Set the collection attributes
+    fn set_attributes(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        self.observe_unfrozen()?;
+
+        // Reject anything that would corrupt the metadata document
+        let attributes = InputReader::trailing(&context.inputs, 0).next_string()?;
+        serde_json::from_str::<serde_json::Value>(&attributes)
+            .map_err(|e| anyhow!("attributes must be valid JSON: {}", e))?;
+        self.set_metadata_field("attributes", &attributes);
+
+        Ok(response)
+    }
+
+    /// Switch the orbital template used for future mints
+    fn set_orbital_template(&self, orbital_template: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        self.observe_unfrozen()?;
+
+        self.set_orbital_template_id(orbital_template);
+
+        Ok(response)
+    }
+
+    /// Permanently freeze the collection
+    fn freeze(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+        self.observe_unfrozen()?;
+
+        self.frozen_height_pointer().set_value::<u64>(self.height());
+
         Ok(response)
     }
 
@@ -316,12 +655,6 @@ impl Collection {
             return Err(anyhow!("Unauthorized caller"));
         }
 
-        // Get the next sequence number
-        let sequence = match self.context() {
-            Ok(context) => context.myself.tx,
-            Err(_) => 0, // This should never happen
-        };
-
         // Get the next index (0-based)
         let index = self.instances_count();
 
@@ -334,24 +667,30 @@ impl Collection {
             inputs: vec![0, index], // Initialize opcode with index
         };
         
-        // Call the orbital template but ignore the response
-        let _orbital_call_response = self.call(
+        // Call the orbital template, which mints the single unit of the new orbital back to us
+        let orbital_call_response = self.call(
             &orbital_cellpack,
             &AlkaneTransferParcel::default(),
             self.fuel()
         )?;
         
-        // Extract the orbital instance ID from the response
-        // In a real implementation, we would parse the response to get the ID
-        // For now, we'll just use a simplified ID
-        let instance_id = AlkaneId {
-            block: 2, // Simplified for demonstration
-            tx: sequence,
-        };
+        // The minted unit in the returned parcel carries the AlkaneId of the new instance,
+        // so anything other than exactly one transfer of one unit is not a valid mint
+        let transfers = orbital_call_response.alkanes.0;
+        if transfers.len() != 1 || transfers[0].value != 1 {
+            return Err(anyhow!(
+                "orbital factory call must return exactly one transfer of 1 unit, got {} transfers",
+                transfers.len()
+            ));
+        }
+        let instance_id = transfers[0].id.clone();
         
         // Add the instance to the registry
         self.add_instance(&instance_id)?;
 
+        // Hand the orbital to the caller so it can deliver it to the buyer
+        response.alkanes.0.push(orbital_transfer);
+
         // Serialize the instance ID and index
         let mut bytes = Vec::with_capacity(48);
         bytes.extend_from_slice(&instance_id.block.to_le_bytes());
@@ -404,12 +743,126 @@ impl Collection {
         Ok(response)
     }
 
-    /// Get the data of the collection
-    fn get_data(&self) -> Result<CallResponse> {
+    /// Get the allowlisted minters
+    fn get_minters(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let minters = self.minters();
+        let mut bytes = Vec::with_capacity(minters.len() * 32);
+        for minter in minters {
+            bytes.extend_from_slice(&minter.block.to_le_bytes());
+            bytes.extend_from_slice(&minter.tx.to_le_bytes());
+        }
+
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get the AlkaneId of the orbital at an index
+    fn get_orbital_at(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let orbital_id = self.orbital_at(index)
+            .ok_or_else(|| anyhow!("orbital index out of range"))?;
+
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&orbital_id.block.to_le_bytes());
+        bytes.extend_from_slice(&orbital_id.tx.to_le_bytes());
+
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get a page of orbital AlkaneIds
+    fn get_orbitals(&self, offset: u128, limit: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Clamp the page to the orbitals that exist
+        let page = registry::page_range(offset, limit, self.instances_count());
+
+        let mut bytes = Vec::with_capacity(((page.end - page.start) as usize) * 32);
+        for index in page {
+            let orbital_id = self.orbital_at(index)
+                .ok_or_else(|| anyhow!("missing orbital at index {}", index))?;
+            bytes.extend_from_slice(&orbital_id.block.to_le_bytes());
+            bytes.extend_from_slice(&orbital_id.tx.to_le_bytes());
+        }
+
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Check whether an alkane is an orbital of this collection
+    fn get_orbital_index(&self, orbital_block: u128, orbital_tx: u128) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        // Create a cellpack to call the container's GetData opcode
+        let orbital_id = AlkaneId {
+            block: orbital_block,
+            tx: orbital_tx,
+        };
+
+        // Serialize as [is_member(16 bytes)][index(16 bytes)]
+        let (is_member, index) = match self.instance_index(&orbital_id) {
+            Some(index) => (1u128, index),
+            None => (0u128, 0u128),
+        };
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&is_member.to_le_bytes());
+        bytes.extend_from_slice(&index.to_le_bytes());
+
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get the collection metadata as a JSON document
+    fn get_metadata(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = serde_json::to_vec(&self.metadata()?)?;
+
+        Ok(response)
+    }
+
+    /// Get the freeze status of the collection
+    fn get_freeze_status(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Serialize as [frozen(16 bytes)][height(16 bytes)]
+        let (frozen, height) = match self.frozen_height() {
+            Some(height) => (1u128, height as u128),
+            None => (0u128, 0u128),
+        };
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&frozen.to_le_bytes());
+        bytes.extend_from_slice(&height.to_le_bytes());
+
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get the orbital template ID used for new mints
+    fn get_orbital_template(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.orbital_template().to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Call a data opcode on the container and return the response bytes
+    fn container_data(&self, inputs: Vec<u128>) -> Result<Vec<u8>> {
         // The container is at AlkaneId { block: 2, tx: self.container_sequence() }
         let container_id = AlkaneId {
             block: 2,
@@ -418,23 +871,74 @@ impl Collection {
         
         let cellpack = Cellpack {
             target: container_id,
-            inputs: vec![1000], // GetData opcode
+            inputs,
         };
         
-        // Call the container's GetData opcode
+        // Call the container
         let call_response = self.staticcall(
             &cellpack,
             &AlkaneTransferParcel::default(),
             <Self as AlkaneResponder>::fuel(&self)
         )?;
-        
+
+        Ok(call_response.data)
+    }
+
+    /// Get the data of the collection
+    fn get_data(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
         // Pass the bytes with NO transform to the caller
-        response.data = call_response.data;
+        response.data = self.container_data(vec![1000])?; // GetData opcode
+
+        Ok(response)
+    }
+
+    /// Get the size of the collection data
+    fn get_data_size(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.container_data(vec![1001])?; // GetDataSize opcode
+
+        Ok(response)
+    }
+
+    /// Get a range of the collection data
+    fn get_data_range(&self, offset: u128, length: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Pass the bytes with NO transform to the caller
+        response.data = self.container_data(vec![1002, offset, length])?; // GetDataRange opcode
+
+        Ok(response)
+    }
+
+    /// Get the MIME type of the collection data
+    fn get_content_type(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.container_data(vec![1003])?; // GetContentType opcode
+
+        Ok(response)
+    }
+
+    /// Get the SHA-256 hash of the collection data
+    fn get_content_hash(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.container_data(vec![1004])?; // GetContentHash opcode
 
         Ok(response)
     }
 }
 
+impl AuthenticatedResponder for Collection {}
+
 impl AlkaneResponder for Collection {
     fn execute(&self) -> Result<CallResponse> {
         // This method should not be called directly when using MessageDispatch
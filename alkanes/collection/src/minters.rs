@@ -0,0 +1,62 @@
+use alkanes_support::id::AlkaneId;
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::sync::Arc;
+
+/// Keyword of the allowlist flags, one per alkane ID
+pub const MINTERS_KEYWORD: &str = "/minters/";
+
+/// Keyword of the list of every alkane ID ever added as a minter
+pub const MINTER_LIST_KEYWORD: &str = "/minter-list";
+
+/// Encode an alkane ID as its block and tx in little-endian u128s
+pub fn alkane_id_bytes(alkane_id: &AlkaneId) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(32);
+    bytes.extend_from_slice(&alkane_id.block.to_le_bytes());
+    bytes.extend_from_slice(&alkane_id.tx.to_le_bytes());
+    bytes
+}
+
+/// Get the pointer to the minter allowlist entry for an alkane ID
+pub fn minter_pointer<P: KeyValuePointer>(alkane_id: &AlkaneId) -> P {
+    P::from_keyword(MINTERS_KEYWORD).select(&alkane_id_bytes(alkane_id))
+}
+
+/// Get the pointer to the list of every alkane ID ever added as a minter
+pub fn minter_list_pointer<P: KeyValuePointer>() -> P {
+    P::from_keyword(MINTER_LIST_KEYWORD)
+}
+
+/// Add an alkane ID to the minter allowlist
+pub fn allow_minter<P: KeyValuePointer>(alkane_id: &AlkaneId) {
+    let mut pointer = minter_pointer::<P>(alkane_id);
+
+    // Only record the ID in the enumeration list the first time it is seen
+    if pointer.get().len() == 0 {
+        minter_list_pointer::<P>().append(Arc::new(alkane_id_bytes(alkane_id)));
+    }
+
+    pointer.set_value::<u8>(0x01);
+}
+
+/// Remove an alkane ID from the minter allowlist
+pub fn disallow_minter<P: KeyValuePointer>(alkane_id: &AlkaneId) {
+    minter_pointer::<P>(alkane_id).set_value::<u8>(0x00);
+}
+
+/// Check if an alkane ID is on the minter allowlist
+pub fn is_minter<P: KeyValuePointer>(alkane_id: &AlkaneId) -> bool {
+    minter_pointer::<P>(alkane_id).get_value::<u8>() == 0x01
+}
+
+/// Get the alkane IDs currently on the minter allowlist, in the order they were first added
+pub fn minters<P: KeyValuePointer>() -> Vec<AlkaneId> {
+    minter_list_pointer::<P>()
+        .get_list()
+        .into_iter()
+        .map(|bytes| AlkaneId {
+            block: u128::from_le_bytes(bytes[0..16].try_into().unwrap()),
+            tx: u128::from_le_bytes(bytes[16..32].try_into().unwrap()),
+        })
+        .filter(|id| is_minter::<P>(id))
+        .collect()
+}
@@ -0,0 +1,22 @@
+use std::ops::Range;
+
+/// Maximum number of orbitals returned by a single GetOrbitals page
+pub const MAX_PAGE_SIZE: u128 = 256;
+
+/// Map a 0-based orbital index to the 1-based sequence number the registry is keyed by
+pub fn sequence_of(index: u128) -> Option<u128> {
+    index.checked_add(1)
+}
+
+/// Map a 1-based registry sequence number back to its 0-based orbital index
+pub fn index_of(sequence: u128) -> Option<u128> {
+    sequence.checked_sub(1)
+}
+
+/// Get the 0-based indices of a page of orbitals, clamped to the orbitals that exist
+/// and to at most `MAX_PAGE_SIZE` entries
+pub fn page_range(offset: u128, limit: u128, count: u128) -> Range<u128> {
+    let start = offset.min(count);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+    start..end
+}
@@ -0,0 +1,91 @@
+use alkanes_support::id::AlkaneId;
+use metashrew_support::index_pointer::KeyValuePointer;
+use orbitals_collection_contract::minters::{
+    allow_minter, disallow_minter, is_minter, minter_list_pointer, minters,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+thread_local! {
+    /// Storage for the test running on this thread
+    static STORAGE: RefCell<HashMap<Vec<u8>, Arc<Vec<u8>>>> = RefCell::new(HashMap::new());
+}
+
+/// A storage pointer backed by a per-thread map, standing in for the runtime's storage
+#[derive(Debug, Clone, Default)]
+struct MemoryPointer(Arc<Vec<u8>>);
+
+impl KeyValuePointer for MemoryPointer {
+    fn wrap(word: &Vec<u8>) -> Self {
+        MemoryPointer(Arc::new(word.clone()))
+    }
+
+    fn unwrap(&self) -> Arc<Vec<u8>> {
+        self.0.clone()
+    }
+
+    fn set(&mut self, v: Arc<Vec<u8>>) {
+        STORAGE.with(|storage| storage.borrow_mut().insert(self.0.to_vec(), v));
+    }
+
+    fn get(&self) -> Arc<Vec<u8>> {
+        STORAGE.with(|storage| storage.borrow().get(self.0.as_ref()).cloned().unwrap_or_default())
+    }
+
+    fn inherits(&mut self, _from: &Self) {}
+}
+
+const SALE: AlkaneId = AlkaneId { block: 2, tx: 10 };
+const OTHER: AlkaneId = AlkaneId { block: 2, tx: 11 };
+
+fn list_length() -> usize {
+    minter_list_pointer::<MemoryPointer>().get_list().len()
+}
+
+#[test]
+fn test_unknown_alkane_is_not_a_minter() {
+    assert!(!is_minter::<MemoryPointer>(&SALE));
+    assert!(minters::<MemoryPointer>().is_empty());
+}
+
+#[test]
+fn test_add_minter() {
+    allow_minter::<MemoryPointer>(&SALE);
+
+    assert!(is_minter::<MemoryPointer>(&SALE));
+    assert!(!is_minter::<MemoryPointer>(&OTHER));
+    assert_eq!(minters::<MemoryPointer>(), vec![SALE]);
+}
+
+#[test]
+fn test_remove_minter() {
+    allow_minter::<MemoryPointer>(&SALE);
+    allow_minter::<MemoryPointer>(&OTHER);
+    disallow_minter::<MemoryPointer>(&SALE);
+
+    assert!(!is_minter::<MemoryPointer>(&SALE));
+    assert_eq!(minters::<MemoryPointer>(), vec![OTHER]);
+}
+
+#[test]
+fn test_readd_minter_keeps_one_list_entry() {
+    allow_minter::<MemoryPointer>(&SALE);
+    disallow_minter::<MemoryPointer>(&SALE);
+    allow_minter::<MemoryPointer>(&SALE);
+    allow_minter::<MemoryPointer>(&SALE);
+
+    assert!(is_minter::<MemoryPointer>(&SALE));
+    assert_eq!(minters::<MemoryPointer>(), vec![SALE]);
+    assert_eq!(list_length(), 1);
+}
+
+#[test]
+fn test_removing_unknown_minter_does_not_list_it() {
+    disallow_minter::<MemoryPointer>(&OTHER);
+    allow_minter::<MemoryPointer>(&OTHER);
+
+    // The flag was already written by the removal, so adding it must still list it once
+    assert!(is_minter::<MemoryPointer>(&OTHER));
+    assert_eq!(minters::<MemoryPointer>(), vec![OTHER]);
+}
@@ -0,0 +1,43 @@
+use orbitals_collection_contract::registry::{index_of, page_range, sequence_of, MAX_PAGE_SIZE};
+
+#[test]
+fn test_registry_is_keyed_from_one() {
+    assert_eq!(sequence_of(0), Some(1));
+    assert_eq!(sequence_of(41), Some(42));
+    assert_eq!(index_of(1), Some(0));
+    assert_eq!(index_of(42), Some(41));
+
+    // No orbital lives at sequence 0, and the last index has no sequence
+    assert_eq!(index_of(0), None);
+    assert_eq!(sequence_of(u128::MAX), None);
+}
+
+#[test]
+fn test_index_and_sequence_round_trip() {
+    for index in [0u128, 1, 255, 256, 1_000_000, u128::MAX - 1] {
+        assert_eq!(sequence_of(index).and_then(index_of), Some(index));
+    }
+}
+
+#[test]
+fn test_page_range_within_count() {
+    assert_eq!(page_range(0, 10, 100), 0..10);
+    assert_eq!(page_range(90, 10, 100), 90..100);
+    assert_eq!(page_range(95, 10, 100), 95..100);
+    assert_eq!(page_range(0, 0, 100), 0..0);
+}
+
+#[test]
+fn test_page_range_clamps_to_max_page_size() {
+    assert_eq!(page_range(0, 1_000, 10_000), 0..MAX_PAGE_SIZE);
+    assert_eq!(page_range(10, u128::MAX, 10_000), 10..10 + MAX_PAGE_SIZE);
+    assert_eq!(page_range(0, MAX_PAGE_SIZE + 1, 10_000).count() as u128, MAX_PAGE_SIZE);
+}
+
+#[test]
+fn test_page_range_past_the_end_is_empty() {
+    assert!(page_range(100, 10, 100).is_empty());
+    assert!(page_range(101, 10, 100).is_empty());
+    assert!(page_range(u128::MAX, u128::MAX, 100).is_empty());
+    assert!(page_range(0, 10, 0).is_empty());
+}
@@ -112,6 +112,16 @@ pub fn derive_orbital_message(input: TokenStream) -> TokenStream {
                     100 => Ok(Self::GetSymbol),
                     101 => Ok(Self::GetTotalSupply),
                     1000 => Ok(Self::GetData),
+                    1001 => Ok(Self::GetDataSize),
+                    1002 => {
+                        if inputs.len() >= 2 {
+                            Ok(Self::GetDataRange { offset: inputs[0], length: inputs[1] })
+                        } else {
+                            Err(anyhow::anyhow!("Missing offset and length parameters for GetDataRange"))
+                        }
+                    },
+                    1003 => Ok(Self::GetContentType),
+                    1004 => Ok(Self::GetContentHash),
                     _ => Err(anyhow::anyhow!("Unknown opcode: {}", opcode)),
                 }
             }
@@ -123,6 +133,10 @@ pub fn derive_orbital_message(input: TokenStream) -> TokenStream {
                     Self::GetSymbol => responder.get_symbol(),
                     Self::GetTotalSupply => responder.get_total_supply(),
                     Self::GetData => responder.get_data(),
+                    Self::GetDataSize => responder.get_data_size(),
+                    Self::GetDataRange { offset, length } => responder.get_data_range(*offset, *length),
+                    Self::GetContentType => responder.get_content_type(),
+                    Self::GetContentHash => responder.get_content_hash(),
                 }
             }
             
@@ -132,7 +146,11 @@ pub fn derive_orbital_message(input: TokenStream) -> TokenStream {
                     {"opcode":99,"name":"GetName","inputs":[],"outputs":[{"type":"String"}]},
                     {"opcode":100,"name":"GetSymbol","inputs":[],"outputs":[{"type":"String"}]},
                     {"opcode":101,"name":"GetTotalSupply","inputs":[],"outputs":[{"type":"u128"}]},
-                    {"opcode":1000,"name":"GetData","inputs":[],"outputs":[{"type":"Vec<u8>"}]}
+                    {"opcode":1000,"name":"GetData","inputs":[],"outputs":[{"type":"Vec<u8>"}]},
+                    {"opcode":1001,"name":"GetDataSize","inputs":[],"outputs":[{"type":"u128"}]},
+                    {"opcode":1002,"name":"GetDataRange","inputs":[{"name":"offset","type":"u128"},{"name":"length","type":"u128"}],"outputs":[{"type":"Vec<u8>"}]},
+                    {"opcode":1003,"name":"GetContentType","inputs":[],"outputs":[{"type":"String"}]},
+                    {"opcode":1004,"name":"GetContentHash","inputs":[],"outputs":[{"type":"Vec<u8>"}]}
                 ]"#.as_bytes().to_vec()
             }
         }
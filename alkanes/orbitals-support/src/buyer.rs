@@ -0,0 +1,64 @@
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::{Script, ScriptBuf, Transaction};
+use metashrew_support::utils::consensus_decode;
+use ordinals::{Artifact, Runestone};
+use protorune_support::protostone::Protostone;
+
+/// A fixed-size key identifying a buyer: the SHA-256 hash of their output script
+pub type BuyerKey = [u8; 32];
+
+/// Get the buyer key for an output script
+pub fn buyer_key(script: &Script) -> BuyerKey {
+    sha256::Hash::hash(script.as_bytes()).to_byte_array()
+}
+
+/// Encode a buyer key as two u128 inputs (the little-endian halves of the hash)
+pub fn buyer_key_to_inputs(key: &BuyerKey) -> [u128; 2] {
+    [
+        u128::from_le_bytes(key[0..16].try_into().unwrap()),
+        u128::from_le_bytes(key[16..32].try_into().unwrap()),
+    ]
+}
+
+/// Decode a buyer key from two u128 inputs
+pub fn buyer_key_from_inputs(low: u128, high: u128) -> BuyerKey {
+    let mut key = [0u8; 32];
+    key[0..16].copy_from_slice(&low.to_le_bytes());
+    key[16..32].copy_from_slice(&high.to_le_bytes());
+    key
+}
+
+/// Get the output script the calling protostone's pointer sends alkanes to
+///
+/// `vout` is the virtual output of the protostone from the call context. The orbitals
+/// bought in the call go to this output, so its script identifies the buyer.
+pub fn pointer_script(tx: &Transaction, vout: u32) -> Result<ScriptBuf> {
+    let runestone = match Runestone::decipher(tx) {
+        Some(Artifact::Runestone(runestone)) => runestone,
+        _ => return Err(anyhow!("unexpected condition: execution occurred with no Protostone present")),
+    };
+    let protostones = Protostone::from_runestone(&runestone)?;
+
+    let index = (vout as usize)
+        .checked_sub(tx.output.len() + 1)
+        .ok_or_else(|| anyhow!("vout {} is not a protostone", vout))?;
+    let message = protostones
+        .get(index)
+        .ok_or_else(|| anyhow!("no protostone at vout {}", vout))?;
+
+    let pointer = message.pointer.ok_or_else(|| anyhow!("no pointer in message"))? as usize;
+    let output = tx
+        .output
+        .get(pointer)
+        .ok_or_else(|| anyhow!("pointer cannot be a protomessage"))?;
+
+    Ok(output.script_pubkey.clone())
+}
+
+/// Get the buyer key for the call from the raw transaction and the protostone's virtual output
+pub fn caller_buyer_key(transaction: &[u8], vout: u32) -> Result<BuyerKey> {
+    let tx = consensus_decode::<Transaction>(&mut std::io::Cursor::new(transaction.to_vec()))
+        .map_err(|e| anyhow!("Failed to parse Bitcoin transaction: {}", e))?;
+    Ok(buyer_key(&pointer_script(&tx, vout)?))
+}
@@ -0,0 +1,133 @@
+use anyhow::{anyhow, Result};
+
+/// Number of bytes packed into each u128 input
+const WORD_SIZE: usize = 16;
+
+/// Encode bytes as a length prefix followed by the bytes packed into little-endian u128 words
+///
+/// This is the inverse of `decode_bytes` and is useful for building cellpack inputs
+pub fn encode_bytes(bytes: &[u8]) -> Vec<u128> {
+    let mut inputs = Vec::with_capacity(1 + (bytes.len() + WORD_SIZE - 1) / WORD_SIZE);
+    inputs.push(bytes.len() as u128);
+
+    for chunk in bytes.chunks(WORD_SIZE) {
+        let mut word = [0u8; WORD_SIZE];
+        word[..chunk.len()].copy_from_slice(chunk);
+        inputs.push(u128::from_le_bytes(word));
+    }
+
+    inputs
+}
+
+/// Encode a string as a length-prefixed sequence of u128 inputs
+pub fn encode_string(value: &str) -> Vec<u128> {
+    encode_bytes(value.as_bytes())
+}
+
+/// Decode length-prefixed bytes from the start of the inputs
+///
+/// Returns the bytes and the number of inputs consumed
+pub fn decode_bytes(inputs: &[u128]) -> Result<(Vec<u8>, usize)> {
+    let length = *inputs.first().ok_or_else(|| anyhow!("missing length prefix"))?;
+    let length = usize::try_from(length).map_err(|_| anyhow!("byte length {} is too large", length))?;
+
+    // Check the words are present before allocating anything
+    let words = length / WORD_SIZE + usize::from(length % WORD_SIZE != 0);
+    if inputs.len() - 1 < words {
+        return Err(anyhow!(
+            "expected {} inputs for {} bytes but only {} remain",
+            words,
+            length,
+            inputs.len() - 1
+        ));
+    }
+
+    let mut bytes = Vec::with_capacity(words * WORD_SIZE);
+    for word in &inputs[1..1 + words] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+
+    // Padding in the last word must be zero so each value has exactly one encoding
+    if bytes[length..].iter().any(|b| *b != 0) {
+        return Err(anyhow!("non-zero padding after {} bytes", length));
+    }
+    bytes.truncate(length);
+
+    Ok((bytes, 1 + words))
+}
+
+/// Decode a length-prefixed UTF-8 string from the start of the inputs
+///
+/// Returns the string and the number of inputs consumed
+pub fn decode_string(inputs: &[u128]) -> Result<(String, usize)> {
+    let (bytes, consumed) = decode_bytes(inputs)?;
+    let value = String::from_utf8(bytes).map_err(|e| anyhow!("invalid UTF-8 string: {}", e))?;
+    Ok((value, consumed))
+}
+
+/// Cursor for reading arguments out of u128 call inputs
+pub struct InputReader<'a> {
+    inputs: &'a [u128],
+    position: usize,
+}
+
+impl<'a> InputReader<'a> {
+    /// Create a reader over the given inputs
+    pub fn new(inputs: &'a [u128]) -> Self {
+        Self { inputs, position: 0 }
+    }
+
+    /// Create a reader over the context inputs that follow the opcode and its fixed arguments
+    pub fn trailing(context_inputs: &'a [u128], fixed_arguments: usize) -> Self {
+        let start = (1 + fixed_arguments).min(context_inputs.len());
+        Self::new(&context_inputs[start..])
+    }
+
+    /// Get the inputs that have not been read yet
+    pub fn remaining(&self) -> &'a [u128] {
+        &self.inputs[self.position..]
+    }
+
+    /// Check if all inputs have been read
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.inputs.len()
+    }
+
+    /// Read the next u128 input
+    pub fn next_u128(&mut self) -> Result<u128> {
+        let value = *self
+            .inputs
+            .get(self.position)
+            .ok_or_else(|| anyhow!("missing input at position {}", self.position))?;
+        self.position += 1;
+        Ok(value)
+    }
+
+    /// Skip over the given number of inputs
+    pub fn skip(&mut self, count: usize) -> Result<()> {
+        if count > self.inputs.len() - self.position.min(self.inputs.len()) {
+            return Err(anyhow!("cannot skip {} inputs at position {}", count, self.position));
+        }
+        self.position += count;
+        Ok(())
+    }
+
+    /// Read the next u128 input, or return the default if the inputs are exhausted
+    pub fn next_u128_or(&mut self, default: u128) -> u128 {
+        self.next_u128().unwrap_or(default)
+    }
+
+    /// Read the next length-prefixed byte string
+    pub fn next_bytes(&mut self) -> Result<Vec<u8>> {
+        let (bytes, consumed) = decode_bytes(self.remaining())?;
+        self.position += consumed;
+        Ok(bytes)
+    }
+
+    /// Read the next length-prefixed UTF-8 string
+    pub fn next_string(&mut self) -> Result<String> {
+        let (value, consumed) = decode_string(self.remaining())?;
+        self.position += consumed;
+        Ok(value)
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::{sha256, Hash};
+
+/// Sale settings an admin can change after initialization
+pub mod config_fields {
+    /// Price per orbital; the value is the new price
+    pub const PRICE: u128 = 1;
+    /// Maximum number of orbitals sold; the value is the new limit
+    pub const LIMIT: u128 = 2;
+    /// Beneficiaries of the proceeds; the value is the new number of beneficiaries and the
+    /// detail the SHA-256 hash of each one's key (its output script on the Bitcoin sale,
+    /// starting with the taproot address) and share in basis points as a little-endian u128,
+    /// followed on the Rust sale by the dust beneficiary's index
+    pub const BENEFICIARIES: u128 = 3;
+    /// Terms of service; the value is the new terms version
+    pub const TERMS: u128 = 4;
+    /// Purchases paused; the value is 1 when paused and 0 when resumed
+    pub const PAUSED: u128 = 5;
+    /// Payment alkane accepted or repriced; the value is its price and the detail its ID
+    pub const PAYMENT_ADDED: u128 = 6;
+    /// Payment alkane no longer accepted; the detail is its ID
+    pub const PAYMENT_REMOVED: u128 = 7;
+    /// Merkle allowlist root; the value is the phase (the end height of the allowlist phase on
+    /// the Bitcoin sale) and the detail the root
+    pub const MERKLE_ROOT: u128 = 8;
+    /// Buyers added to a phase allowlist; the value is the phase and the detail the SHA-256
+    /// hash of their keys in the order given
+    pub const BUYERS_ALLOWED: u128 = 9;
+    /// Buyers removed from a phase allowlist; the value is the phase and the detail the SHA-256
+    /// hash of their keys in the order given
+    pub const BUYERS_DISALLOWED: u128 = 10;
+}
+
+/// Size of an encoded config change
+pub const CONFIG_CHANGE_SIZE: usize = 80;
+
+/// Detail for changes that carry nothing beyond their value
+pub const NO_DETAIL: [u8; 32] = [0u8; 32];
+
+/// A change to a sale setting and the block height it was made at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Block height of the change
+    pub height: u128,
+    /// Setting changed, from `config_fields`
+    pub field: u128,
+    /// New value of the setting
+    pub value: u128,
+    /// What the value alone cannot hold, such as an alkane ID or a hash (see `config_fields`)
+    pub detail: [u8; 32],
+}
+
+impl ConfigChange {
+    /// Encode the change as its height, field and value in little-endian u128s followed by
+    /// the detail
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CONFIG_CHANGE_SIZE);
+        bytes.extend_from_slice(&self.height.to_le_bytes());
+        bytes.extend_from_slice(&self.field.to_le_bytes());
+        bytes.extend_from_slice(&self.value.to_le_bytes());
+        bytes.extend_from_slice(&self.detail);
+        bytes
+    }
+
+    /// Decode a change from its bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != CONFIG_CHANGE_SIZE {
+            return Err(anyhow!("config change must be {} bytes, got {}", CONFIG_CHANGE_SIZE, bytes.len()));
+        }
+
+        Ok(Self {
+            height: u128::from_le_bytes(bytes[0..16].try_into().unwrap()),
+            field: u128::from_le_bytes(bytes[16..32].try_into().unwrap()),
+            value: u128::from_le_bytes(bytes[32..48].try_into().unwrap()),
+            detail: bytes[48..80].try_into().unwrap(),
+        })
+    }
+}
+
+/// Get the detail for a change naming an alkane: its block and tx in little-endian u128s
+pub fn alkane_detail(block: u128, tx: u128) -> [u8; 32] {
+    let mut detail = [0u8; 32];
+    detail[0..16].copy_from_slice(&block.to_le_bytes());
+    detail[16..32].copy_from_slice(&tx.to_le_bytes());
+    detail
+}
+
+/// Get the detail for a change too large to log in full: the SHA-256 hash of its encoding
+pub fn digest_detail(bytes: &[u8]) -> [u8; 32] {
+    sha256::Hash::hash(bytes).to_byte_array()
+}
@@ -1,9 +1,37 @@
 use alkanes_runtime::{runtime::AlkaneResponder, storage::StoragePointer, token::Token};
 use alkanes_support::{parcel::AlkaneTransferParcel, response::CallResponse, id::AlkaneId, cellpack::Cellpack};
 use anyhow::{anyhow, Result};
+use bitcoin::hashes::{sha256, Hash};
 use metashrew_support::index_pointer::KeyValuePointer;
 use std::sync::Arc;
 
+// Length-prefixed string codec for u128 call inputs
+pub mod codec;
+
+// Identifying buyers by the output their protostone points to
+pub mod buyer;
+
+// Merkle allowlist proofs
+pub mod merkle;
+
+// Signed mint vouchers
+pub mod voucher;
+
+// Optional sections passed with sale purchases
+pub mod purchase;
+
+// Splitting sale proceeds between beneficiaries
+pub mod split;
+
+// Naming referrers in Bitcoin sale transactions
+pub mod referral;
+
+// Logging sale configuration changes
+pub mod config;
+
+// Versioned, hash-committed terms of service
+pub mod terms;
+
 // Example implementations of BytesTransform
 pub mod examples;
 
@@ -14,6 +42,36 @@ pub mod custom_orbital_example;
 pub trait BytesTransform: Send + Sync {
     /// Transform the input bytes based on the index and sequence
     fn transform(&self, input: &[u8], index: u128, sequence: u128) -> Vec<u8>;
+
+    /// Whether the transform can be applied to byte ranges independently
+    ///
+    /// A chunkable transform must preserve the length of its input and the output
+    /// for a range must not depend on bytes outside of it. Orbitals then read ranges
+    /// straight from the container instead of loading and transforming all of the data.
+    fn chunkable(&self) -> bool {
+        false
+    }
+
+    /// Transform a byte range that starts at `offset` within the full data
+    ///
+    /// Only called when `chunkable` returns true
+    fn transform_chunk(&self, input: &[u8], _offset: u128, index: u128, sequence: u128) -> Vec<u8> {
+        self.transform(input, index, sequence)
+    }
+
+    /// The MIME type of the transformed output
+    ///
+    /// None means the output has the same type as the container data
+    fn content_type(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether the output is always identical to the input
+    ///
+    /// Orbitals use this to serve the container's content hash instead of hashing the data themselves
+    fn is_identity(&self) -> bool {
+        false
+    }
 }
 
 /// A transform that passes the bytes through without modification
@@ -23,6 +81,26 @@ impl BytesTransform for IdentityTransform {
     fn transform(&self, input: &[u8], _index: u128, _sequence: u128) -> Vec<u8> {
         input.to_vec()
     }
+
+    fn chunkable(&self) -> bool {
+        true
+    }
+
+    fn is_identity(&self) -> bool {
+        true
+    }
+}
+
+/// Compute the SHA-256 content hash of the data
+pub fn content_hash(data: &[u8]) -> [u8; 32] {
+    sha256::Hash::hash(data).to_byte_array()
+}
+
+/// Get the bytes in `[offset, offset + length)`, clamped to the end of the data
+pub fn data_range(data: &[u8], offset: u128, length: u128) -> &[u8] {
+    let start = usize::try_from(offset).unwrap_or(usize::MAX).min(data.len());
+    let length = usize::try_from(length).unwrap_or(usize::MAX).min(data.len() - start);
+    &data[start..start + length]
 }
 
 /// Trait for orbital alkanes
@@ -186,39 +264,118 @@ pub trait Orbital: AlkaneResponder + Token {
     /// Get the data transform to apply
     fn get_transform(&self) -> Box<dyn BytesTransform>;
 
-    /// Get the data of the orbital (proxies to collection with transform)
-    fn get_data(&self) -> Result<CallResponse> {
-        let context = self.context()?;
-        let mut response = CallResponse::forward(&context.incoming_alkanes);
-
-        // Get the collection alkane ID
-        let collection_id = self.collection_ref();
-        
-        // Create a cellpack to call the collection's GetData opcode
+    /// Call a data opcode on the collection and return the response bytes
+    fn collection_data(&self, inputs: Vec<u128>) -> Result<Vec<u8>> {
+        // Create a cellpack to call the collection's data opcode
         let cellpack = Cellpack {
-            target: collection_id,
-            inputs: vec![1000, Orbital::sequence(self)], // GetData opcode with sequence number
+            target: self.collection_ref(),
+            inputs,
         };
-        
-        // Call the collection's GetData opcode
+
+        // Call the collection
         let call_response = self.staticcall(
             &cellpack,
             &AlkaneTransferParcel::default(),
             Orbital::fuel(self)
         )?;
-        
-        // Get the transform
-        let transform = self.get_transform();
-        
+
+        Ok(call_response.data)
+    }
+
+    /// Get all of the data with the transform applied
+    fn transformed_data(&self) -> Result<Vec<u8>> {
+        // GetData opcode with sequence number
+        let data = self.collection_data(vec![1000, Orbital::sequence(self)])?;
+
         // Apply the transform to the data
-        let transformed_data = transform.transform(
-            &call_response.data,
+        Ok(self.get_transform().transform(
+            &data,
             self.index(),
             Orbital::sequence(self)
-        );
-        
+        ))
+    }
+
+    /// Get the data of the orbital (proxies to collection with transform)
+    fn get_data(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
         // Return the transformed data
-        response.data = transformed_data;
+        response.data = self.transformed_data()?;
+
+        Ok(response)
+    }
+
+    /// Get the size of the orbital's data as a u128
+    fn get_data_size(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        if self.get_transform().chunkable() {
+            // Chunkable transforms preserve the length, so the container size is the answer
+            response.data = self.collection_data(vec![1001])?;
+        } else {
+            // Otherwise the size is only known after transforming all of the data
+            let size = self.transformed_data()?.len() as u128;
+            response.data = size.to_le_bytes().to_vec();
+        }
+
+        Ok(response)
+    }
+
+    /// Get the bytes of the orbital's data in `[offset, offset + length)`
+    ///
+    /// The range is clamped to the end of the data
+    fn get_data_range(&self, offset: u128, length: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let transform = self.get_transform();
+        if transform.chunkable() {
+            // Read only the requested range from the container and transform it in place
+            let chunk = self.collection_data(vec![1002, offset, length])?;
+            response.data = transform.transform_chunk(
+                &chunk,
+                offset,
+                self.index(),
+                Orbital::sequence(self)
+            );
+        } else {
+            // Fall back to transforming all of the data and slicing the result
+            let data = self.transformed_data()?;
+            response.data = data_range(&data, offset, length).to_vec();
+        }
+
+        Ok(response)
+    }
+
+    /// Get the MIME type of the orbital's data
+    ///
+    /// Uses the transform's declared output type, or the container's type if it has none
+    fn get_content_type(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = match self.get_transform().content_type() {
+            Some(content_type) => content_type.into_bytes(),
+            None => self.collection_data(vec![1003])?, // GetContentType opcode
+        };
+
+        Ok(response)
+    }
+
+    /// Get the SHA-256 hash of the orbital's data
+    fn get_content_hash(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        if self.get_transform().is_identity() {
+            // The data is unchanged, so the container's hash applies
+            response.data = self.collection_data(vec![1004])?; // GetContentHash opcode
+        } else {
+            // Hash the transformed output
+            response.data = content_hash(&self.transformed_data()?).to_vec();
+        }
 
         Ok(response)
     }
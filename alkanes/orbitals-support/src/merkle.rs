@@ -0,0 +1,108 @@
+use crate::buyer::BuyerKey;
+use crate::codec::InputReader;
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::{sha256, Hash};
+
+/// A node in an allowlist Merkle tree
+pub type MerkleHash = [u8; 32];
+
+/// Most proof hashes accepted, enough for a tree of 2^32 leaves
+pub const MAX_PROOF_LENGTH: usize = 32;
+
+/// Get the leaf hash for a buyer allowed up to `max_quantity` orbitals
+///
+/// The leaf is `sha256(buyer_key || max_quantity as 16 little-endian bytes)`
+pub fn leaf_hash(buyer: &BuyerKey, max_quantity: u128) -> MerkleHash {
+    let mut bytes = Vec::with_capacity(48);
+    bytes.extend_from_slice(buyer);
+    bytes.extend_from_slice(&max_quantity.to_le_bytes());
+    sha256::Hash::hash(&bytes).to_byte_array()
+}
+
+/// Hash two nodes into their parent, ordering them first so proofs need no position bits
+pub fn hash_pair(a: &MerkleHash, b: &MerkleHash) -> MerkleHash {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut bytes = [0u8; 64];
+    bytes[0..32].copy_from_slice(first);
+    bytes[32..64].copy_from_slice(second);
+    sha256::Hash::hash(&bytes).to_byte_array()
+}
+
+/// Check a proof leads from the leaf to the root
+pub fn verify(root: &MerkleHash, leaf: &MerkleHash, proof: &[MerkleHash]) -> bool {
+    let computed = proof.iter().fold(*leaf, |node, sibling| hash_pair(&node, sibling));
+    computed == *root
+}
+
+/// Encode a hash as two u128 inputs (its little-endian halves)
+pub fn hash_to_inputs(hash: &MerkleHash) -> [u128; 2] {
+    [
+        u128::from_le_bytes(hash[0..16].try_into().unwrap()),
+        u128::from_le_bytes(hash[16..32].try_into().unwrap()),
+    ]
+}
+
+/// Decode a hash from two u128 inputs
+pub fn hash_from_inputs(low: u128, high: u128) -> MerkleHash {
+    let mut hash = [0u8; 32];
+    hash[0..16].copy_from_slice(&low.to_le_bytes());
+    hash[16..32].copy_from_slice(&high.to_le_bytes());
+    hash
+}
+
+/// Read a proof encoded as the number of hashes followed by each hash as two u128 inputs
+pub fn read_proof(reader: &mut InputReader) -> Result<Vec<MerkleHash>> {
+    let length = reader.next_u128()?;
+    if length > MAX_PROOF_LENGTH as u128 {
+        return Err(anyhow!("proof of {} hashes exceeds the maximum of {}", length, MAX_PROOF_LENGTH));
+    }
+
+    let mut proof = Vec::with_capacity(length as usize);
+    for _ in 0..length {
+        proof.push(hash_from_inputs(reader.next_u128()?, reader.next_u128()?));
+    }
+    Ok(proof)
+}
+
+/// Hash each pair of nodes into the level above, promoting an unpaired last node
+fn next_level(level: &[MerkleHash]) -> Vec<MerkleHash> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => hash_pair(a, b),
+            [a] => *a,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Build the root of a tree over the leaves, promoting an unpaired node to the next level
+///
+/// This mirrors the host-side tool and is mainly useful for tests
+pub fn root(leaves: &[MerkleHash]) -> Option<MerkleHash> {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.first().copied()
+}
+
+/// Build the proof for the leaf at `index` in a tree over the leaves
+pub fn proof(leaves: &[MerkleHash], index: usize) -> Option<Vec<MerkleHash>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    let mut index = index;
+    while level.len() > 1 {
+        let sibling = index ^ 1;
+        if sibling < level.len() {
+            proof.push(level[sibling]);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    Some(proof)
+}
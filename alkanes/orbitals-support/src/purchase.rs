@@ -0,0 +1,73 @@
+use crate::buyer::{buyer_key_from_inputs, BuyerKey};
+use crate::codec::InputReader;
+use crate::merkle::{self, MerkleHash};
+use crate::terms::TermsHash;
+use crate::voucher::Voucher;
+use anyhow::{anyhow, Result};
+
+/// Tags for the optional sections in the sale Purchase inputs
+pub mod purchase_tags {
+    /// Merkle allowlist proof: the buyer's maximum quantity, the number of proof hashes
+    /// and each hash as two u128 inputs (see `merkle`)
+    pub const MERKLE_PROOF: u128 = 1;
+    /// Signed mint voucher (see `voucher::Voucher::read`)
+    pub const VOUCHER: u128 = 2;
+    /// Referrer: the buyer key of the referrer's output script as two u128 inputs
+    pub const REFERRER: u128 = 3;
+    /// Terms of service acceptance: the SHA-256 hash of the terms as two u128 inputs
+    pub const TERMS: u128 = 4;
+}
+
+/// Optional proofs and settings a buyer passes with a purchase
+#[derive(Debug, Default)]
+pub struct PurchaseOptions {
+    /// The buyer's maximum quantity and the proof of their Merkle allowlist leaf
+    pub merkle_proof: Option<(u128, Vec<MerkleHash>)>,
+    /// A mint voucher signed by the sale admin
+    pub voucher: Option<Voucher>,
+    /// The buyer key of the referrer credited with a cut of the payment
+    pub referrer: Option<BuyerKey>,
+    /// The hash of the terms of service the buyer accepts
+    pub terms: Option<TermsHash>,
+}
+
+impl PurchaseOptions {
+    /// Read tagged sections until the inputs run out
+    pub fn read(reader: &mut InputReader) -> Result<Self> {
+        let mut options = Self::default();
+        while !reader.is_empty() {
+            match reader.next_u128()? {
+                purchase_tags::MERKLE_PROOF => {
+                    let max_quantity = reader.next_u128()?;
+                    options.merkle_proof = Some((max_quantity, merkle::read_proof(reader)?));
+                },
+                purchase_tags::VOUCHER => options.voucher = Some(Voucher::read(reader)?),
+                purchase_tags::REFERRER => {
+                    options.referrer = Some(buyer_key_from_inputs(reader.next_u128()?, reader.next_u128()?));
+                },
+                purchase_tags::TERMS => {
+                    options.terms = Some(merkle::hash_from_inputs(reader.next_u128()?, reader.next_u128()?));
+                },
+                tag => return Err(anyhow!("Unknown purchase section {}", tag)),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Get how many orbitals a buyer may take in one purchase under the mint caps
+///
+/// A cap of 0 means no cap. The per-buyer cap only applies when the buyer is known, with
+/// `buyer_minted` the number they have already minted.
+pub fn mint_allowance(max_per_purchase: u128, max_per_buyer: u128, buyer_minted: Option<u128>) -> u128 {
+    let mut allowance = match max_per_purchase {
+        0 => u128::MAX,
+        max => max,
+    };
+    if let (max, Some(minted)) = (max_per_buyer, buyer_minted) {
+        if max != 0 {
+            allowance = allowance.min(max.saturating_sub(minted));
+        }
+    }
+    allowance
+}
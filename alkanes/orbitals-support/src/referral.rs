@@ -0,0 +1,40 @@
+use bitcoin::opcodes::all::OP_RETURN;
+use bitcoin::script::{Builder, Instruction, PushBytesBuf};
+use bitcoin::{Script, ScriptBuf, Transaction};
+
+/// Marker pushed first in the OP_RETURN output that names a Bitcoin sale referrer
+pub const REFERRAL_MAGIC: [u8; 12] = *b"orbitals/ref";
+
+/// Build the OP_RETURN output script that names a referrer by the output script they are paid at
+pub fn referral_output_script(referrer: &Script) -> ScriptBuf {
+    let script = PushBytesBuf::try_from(referrer.as_bytes().to_vec())
+        .expect("output scripts fit in a single push");
+    Builder::new()
+        .push_opcode(OP_RETURN)
+        .push_slice(REFERRAL_MAGIC)
+        .push_slice(script)
+        .into_script()
+}
+
+/// Find the referrer named by the first referral OP_RETURN output of a transaction
+///
+/// The output pushes `REFERRAL_MAGIC` followed by the referrer's output script
+pub fn referrer_script(tx: &Transaction) -> Option<ScriptBuf> {
+    tx.output
+        .iter()
+        .filter(|output| output.script_pubkey.is_op_return())
+        .find_map(|output| {
+            let mut pushes = output.script_pubkey.instructions().skip(1).map_while(|instruction| {
+                match instruction {
+                    Ok(Instruction::PushBytes(bytes)) => Some(bytes.as_bytes().to_vec()),
+                    _ => None,
+                }
+            });
+            match (pushes.next(), pushes.next()) {
+                (Some(magic), Some(script)) if magic == REFERRAL_MAGIC && !script.is_empty() => {
+                    Some(ScriptBuf::from_bytes(script))
+                },
+                _ => None,
+            }
+        })
+}
@@ -0,0 +1,50 @@
+use anyhow::{anyhow, Result};
+
+/// Basis points in a whole (100%)
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Most beneficiaries a sale can split its proceeds between
+pub const MAX_BENEFICIARIES: usize = 16;
+
+/// Check a set of basis-point shares covers the whole amount
+///
+/// `dust` is the index of the share that receives the rounding remainder
+pub fn validate_shares(shares: &[u128], dust: usize) -> Result<()> {
+    if shares.is_empty() {
+        return Err(anyhow!("No beneficiaries given"));
+    }
+    if shares.len() > MAX_BENEFICIARIES {
+        return Err(anyhow!("At most {} beneficiaries are supported", MAX_BENEFICIARIES));
+    }
+    if dust >= shares.len() {
+        return Err(anyhow!("Dust beneficiary {} is out of range", dust));
+    }
+
+    let total = shares
+        .iter()
+        .try_fold(0u128, |total, bps| total.checked_add(*bps))
+        .ok_or_else(|| anyhow!("Beneficiary shares overflow"))?;
+    if total != BPS_DENOMINATOR {
+        return Err(anyhow!("Beneficiary shares add up to {} bps instead of {}", total, BPS_DENOMINATOR));
+    }
+    Ok(())
+}
+
+/// Get the part of an amount a basis-point share is owed, rounding down
+pub fn share_of(amount: u128, bps: u128) -> u128 {
+    // Split the amount so the multiplication cannot overflow for shares up to the whole
+    let bps = bps.min(BPS_DENOMINATOR);
+    amount / BPS_DENOMINATOR * bps + amount % BPS_DENOMINATOR * bps / BPS_DENOMINATOR
+}
+
+/// Split an amount by basis-point shares, giving the rounding remainder to the `dust` share
+///
+/// The parts always add up to the amount
+pub fn split_shares(amount: u128, shares: &[u128], dust: usize) -> Vec<u128> {
+    let mut parts: Vec<u128> = shares.iter().map(|bps| share_of(amount, *bps)).collect();
+    let remainder = amount - parts.iter().sum::<u128>();
+    if let Some(part) = parts.get_mut(dust) {
+        *part += remainder;
+    }
+    parts
+}
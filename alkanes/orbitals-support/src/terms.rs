@@ -0,0 +1,119 @@
+use crate::codec::InputReader;
+use crate::merkle::hash_from_inputs;
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::{sha256, Hash};
+
+/// Ways a version of the terms of service can be published
+pub mod terms_kinds {
+    /// The full text, committed to by its SHA-256 hash
+    pub const TEXT: u128 = 0;
+    /// The SHA-256 hash of a document and the URI it can be fetched from
+    pub const HASH_URI: u128 = 1;
+}
+
+/// A SHA-256 hash of the terms of service
+pub type TermsHash = [u8; 32];
+
+/// Size of the fixed part of an encoded terms version: the hash, height and kind
+const HEADER_SIZE: usize = 64;
+
+/// A published version of the terms of service
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermsVersion {
+    /// SHA-256 hash buyers accept the version by
+    pub hash: TermsHash,
+    /// Block height the version was published at
+    pub height: u128,
+    /// Kind from `terms_kinds`
+    pub kind: u128,
+    /// The text, or the URI for hash-committed terms
+    pub body: String,
+}
+
+impl TermsVersion {
+    /// Create a version from the full text
+    pub fn from_text(text: &str, height: u128) -> Self {
+        Self {
+            hash: sha256::Hash::hash(text.as_bytes()).to_byte_array(),
+            height,
+            kind: terms_kinds::TEXT,
+            body: text.to_string(),
+        }
+    }
+
+    /// Read a version encoded as its kind followed by the length-prefixed text, or by the
+    /// hash as two u128 inputs (its little-endian halves) and the length-prefixed URI
+    pub fn read(reader: &mut InputReader, height: u128) -> Result<Self> {
+        match reader.next_u128()? {
+            terms_kinds::TEXT => {
+                let text = reader.next_string()?;
+                if text.is_empty() {
+                    return Err(anyhow!("Terms of service cannot be empty"));
+                }
+                Ok(Self::from_text(&text, height))
+            },
+            terms_kinds::HASH_URI => {
+                let hash = hash_from_inputs(reader.next_u128()?, reader.next_u128()?);
+                let uri = reader.next_string()?;
+                if hash == [0u8; 32] || uri.is_empty() {
+                    return Err(anyhow!("Hash-committed terms need a hash and a URI"));
+                }
+                Ok(Self { hash, height, kind: terms_kinds::HASH_URI, body: uri })
+            },
+            kind => Err(anyhow!("Unknown terms kind {}", kind)),
+        }
+    }
+
+    /// Encode the version as its hash, height and kind followed by the body
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + self.body.len());
+        bytes.extend_from_slice(&self.hash);
+        bytes.extend_from_slice(&self.height.to_le_bytes());
+        bytes.extend_from_slice(&self.kind.to_le_bytes());
+        bytes.extend_from_slice(self.body.as_bytes());
+        bytes
+    }
+
+    /// Decode a version from its bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(anyhow!("terms version must be at least {} bytes", HEADER_SIZE));
+        }
+
+        Ok(Self {
+            hash: bytes[0..32].try_into().unwrap(),
+            height: u128::from_le_bytes(bytes[32..48].try_into().unwrap()),
+            kind: u128::from_le_bytes(bytes[48..64].try_into().unwrap()),
+            body: String::from_utf8(bytes[HEADER_SIZE..].to_vec())
+                .map_err(|e| anyhow!("invalid UTF-8 terms: {}", e))?,
+        })
+    }
+}
+
+/// Encode a terms history for views: the number of versions followed by each version's hash,
+/// height, kind, body length as a u128 and body
+pub fn encode_history(history: &[TermsVersion]) -> Vec<u8> {
+    let mut bytes = (history.len() as u128).to_le_bytes().to_vec();
+    for version in history {
+        bytes.extend_from_slice(&version.hash);
+        bytes.extend_from_slice(&version.height.to_le_bytes());
+        bytes.extend_from_slice(&version.kind.to_le_bytes());
+        bytes.extend_from_slice(&(version.body.len() as u128).to_le_bytes());
+        bytes.extend_from_slice(version.body.as_bytes());
+    }
+    bytes
+}
+
+/// Check the terms hash a buyer passed against the current version
+///
+/// Returns the version number accepted (counting from 1), or None if the buyer passed no
+/// hash and acceptance is optional
+pub fn accepted_version(history: &[TermsVersion], accepted: Option<&TermsHash>, required: bool) -> Result<Option<u128>> {
+    let current = history.len() as u128;
+    match (accepted, history.last()) {
+        (Some(hash), Some(version)) if *hash == version.hash => Ok(Some(current)),
+        (Some(_), _) => Err(anyhow!("Terms of service hash does not match version {}", current)),
+        (None, _) if required => Err(anyhow!("Purchases must accept the terms of service by passing their hash")),
+        (None, _) => Ok(None),
+    }
+}
@@ -0,0 +1,99 @@
+use crate::buyer::BuyerKey;
+use crate::codec::InputReader;
+use alkanes_support::id::AlkaneId;
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::secp256k1::{schnorr, Message, Secp256k1, XOnlyPublicKey};
+
+/// Domain tag hashed into every voucher so a signature cannot be reused for other messages
+pub const VOUCHER_TAG: &[u8] = b"orbitals/voucher";
+
+/// A mint voucher signed off-chain by the sale admin
+///
+/// The voucher lets one buyer mint up to `quantity` orbitals at `price` each, in a single
+/// purchase made at or before block `expiry` (0 for no expiry). Each nonce can only be used once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Voucher {
+    /// Most orbitals the voucher covers
+    pub quantity: u128,
+    /// Price per orbital
+    pub price: u128,
+    /// Last block height the voucher can be used at (0 for no expiry)
+    pub expiry: u128,
+    /// Unique number that stops the voucher being replayed
+    pub nonce: u128,
+    /// BIP-340 Schnorr signature over the voucher digest
+    pub signature: [u8; 64],
+}
+
+impl Voucher {
+    /// Read a voucher encoded as its quantity, price, expiry and nonce followed by the
+    /// signature as four u128 inputs (its little-endian quarters)
+    pub fn read(reader: &mut InputReader) -> Result<Self> {
+        let quantity = reader.next_u128()?;
+        let price = reader.next_u128()?;
+        let expiry = reader.next_u128()?;
+        let nonce = reader.next_u128()?;
+
+        let mut signature = [0u8; 64];
+        for chunk in signature.chunks_mut(16) {
+            chunk.copy_from_slice(&reader.next_u128()?.to_le_bytes());
+        }
+
+        Ok(Self { quantity, price, expiry, nonce, signature })
+    }
+
+    /// Encode the voucher as u128 inputs in the order `read` expects
+    pub fn to_inputs(&self) -> Vec<u128> {
+        let mut inputs = vec![self.quantity, self.price, self.expiry, self.nonce];
+        for chunk in self.signature.chunks(16) {
+            inputs.push(u128::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        inputs
+    }
+
+    /// Get the digest the admin signs for this voucher
+    pub fn digest(&self, sale: &AlkaneId, buyer: &BuyerKey) -> [u8; 32] {
+        voucher_digest(sale, buyer, self.quantity, self.price, self.expiry, self.nonce)
+    }
+
+    /// Check the voucher is signed by the admin for this sale and buyer and has not expired
+    pub fn verify(&self, sale: &AlkaneId, buyer: &BuyerKey, signer: &[u8; 32], height: u128) -> Result<()> {
+        if self.expiry != 0 && height > self.expiry {
+            return Err(anyhow!("Voucher expired at height {}", self.expiry));
+        }
+
+        let signer = XOnlyPublicKey::from_slice(signer).map_err(|e| anyhow!("Invalid voucher signer: {}", e))?;
+        let signature = schnorr::Signature::from_slice(&self.signature)
+            .map_err(|e| anyhow!("Invalid voucher signature: {}", e))?;
+        let message = Message::from_digest(self.digest(sale, buyer));
+
+        Secp256k1::verification_only()
+            .verify_schnorr(&signature, &message, &signer)
+            .map_err(|_| anyhow!("Voucher signature does not match the signer"))
+    }
+}
+
+/// Get the digest the admin signs for a voucher
+///
+/// The digest is `sha256(VOUCHER_TAG || sale block || sale tx || buyer key || quantity || price
+/// || expiry || nonce)`, with every number as 16 little-endian bytes
+pub fn voucher_digest(
+    sale: &AlkaneId,
+    buyer: &BuyerKey,
+    quantity: u128,
+    price: u128,
+    expiry: u128,
+    nonce: u128
+) -> [u8; 32] {
+    let mut bytes = Vec::with_capacity(VOUCHER_TAG.len() + 128);
+    bytes.extend_from_slice(VOUCHER_TAG);
+    bytes.extend_from_slice(&sale.block.to_le_bytes());
+    bytes.extend_from_slice(&sale.tx.to_le_bytes());
+    bytes.extend_from_slice(buyer);
+    bytes.extend_from_slice(&quantity.to_le_bytes());
+    bytes.extend_from_slice(&price.to_le_bytes());
+    bytes.extend_from_slice(&expiry.to_le_bytes());
+    bytes.extend_from_slice(&nonce.to_le_bytes());
+    sha256::Hash::hash(&bytes).to_byte_array()
+}
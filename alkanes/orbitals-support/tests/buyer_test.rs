@@ -0,0 +1,119 @@
+use anyhow::Result;
+use bitcoin::absolute::LockTime;
+use bitcoin::consensus::serialize;
+use bitcoin::transaction::Version;
+use bitcoin::{Amount, ScriptBuf, Transaction, TxOut};
+use ordinals::Runestone;
+use orbitals_support::buyer::{buyer_key, caller_buyer_key, pointer_script};
+use protorune_support::protostone::{Protostone, Protostones};
+
+/// Alkanes protocol tag
+const PROTOCOL_TAG: u128 = 1;
+
+fn script(byte: u8) -> ScriptBuf {
+    let mut script = vec![0x51, 0x20];
+    script.extend_from_slice(&[byte; 32]);
+    ScriptBuf::from_bytes(script)
+}
+
+fn protostone(pointer: Option<u32>) -> Protostone {
+    Protostone {
+        burn: None,
+        message: vec![],
+        edicts: vec![],
+        refund: pointer,
+        pointer,
+        from: None,
+        protocol_tag: PROTOCOL_TAG,
+    }
+}
+
+/// Build a transaction paying the given scripts, followed by a runestone carrying the protostones
+fn transaction(scripts: Vec<ScriptBuf>, protostones: Vec<Protostone>) -> Result<Transaction> {
+    let runestone = Runestone {
+        protocol: Some(protostones.encipher()?),
+        ..Default::default()
+    };
+
+    let mut output: Vec<TxOut> = scripts
+        .into_iter()
+        .map(|script_pubkey| TxOut { value: Amount::from_sat(546), script_pubkey })
+        .collect();
+    output.push(TxOut { value: Amount::ZERO, script_pubkey: runestone.encipher() });
+
+    Ok(Transaction { version: Version::TWO, lock_time: LockTime::ZERO, input: vec![], output })
+}
+
+#[test]
+fn test_each_protostone_resolves_its_own_pointer() -> Result<()> {
+    // Two payment outputs and the runestone, so the protostones sit at virtual vouts 4 and 5
+    let tx = transaction(vec![script(1), script(2)], vec![protostone(Some(1)), protostone(Some(0))])?;
+
+    assert_eq!(pointer_script(&tx, 4)?, script(2));
+    assert_eq!(pointer_script(&tx, 5)?, script(1));
+    Ok(())
+}
+
+#[test]
+fn test_real_outputs_are_not_protostones() -> Result<()> {
+    let tx = transaction(vec![script(1), script(2)], vec![protostone(Some(0))])?;
+
+    // The real outputs and the vout the runtime reserves after them do not name a protostone
+    for vout in 0..=3 {
+        assert!(pointer_script(&tx, vout).is_err(), "vout {}", vout);
+    }
+    assert_eq!(pointer_script(&tx, 4)?, script(1));
+    Ok(())
+}
+
+#[test]
+fn test_vout_past_the_protostones_is_rejected() -> Result<()> {
+    let tx = transaction(vec![script(1)], vec![protostone(Some(0)), protostone(Some(0))])?;
+
+    assert!(pointer_script(&tx, 3).is_ok());
+    assert!(pointer_script(&tx, 4).is_ok());
+    assert!(pointer_script(&tx, 5).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_missing_pointer_is_rejected() -> Result<()> {
+    let tx = transaction(vec![script(1)], vec![protostone(Some(0)), protostone(None)])?;
+
+    assert_eq!(pointer_script(&tx, 3)?, script(1));
+    assert!(pointer_script(&tx, 4).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_pointer_to_a_protomessage_is_rejected() -> Result<()> {
+    // The first protostone points at the second one's virtual vout rather than a real output
+    let tx = transaction(vec![script(1)], vec![protostone(Some(4)), protostone(Some(0))])?;
+
+    assert!(pointer_script(&tx, 3).is_err());
+    assert_eq!(pointer_script(&tx, 4)?, script(1));
+    Ok(())
+}
+
+#[test]
+fn test_transaction_without_runestone_is_rejected() {
+    let tx = Transaction {
+        version: Version::TWO,
+        lock_time: LockTime::ZERO,
+        input: vec![],
+        output: vec![TxOut { value: Amount::from_sat(546), script_pubkey: script(1) }],
+    };
+
+    assert!(pointer_script(&tx, 2).is_err());
+}
+
+#[test]
+fn test_caller_buyer_key_hashes_the_pointer_script() -> Result<()> {
+    let tx = transaction(vec![script(1), script(2)], vec![protostone(Some(0)), protostone(Some(1))])?;
+    let raw = serialize(&tx);
+
+    assert_eq!(caller_buyer_key(&raw, 4)?, buyer_key(&script(1)));
+    assert_eq!(caller_buyer_key(&raw, 5)?, buyer_key(&script(2)));
+    assert!(caller_buyer_key(&raw[..raw.len() - 1], 4).is_err());
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use anyhow::Result;
+use orbitals_support::codec::{decode_bytes, decode_string, encode_bytes, encode_string, InputReader};
+
+#[test]
+fn test_string_round_trip() -> Result<()> {
+    for value in [
+        "",
+        "a",
+        "Orbitals",
+        "exactly sixteen!",
+        "thirty-two bytes of text, padded",
+        "a string that is longer than one word but not aligned",
+        "ünïcödé ✓",
+    ] {
+        let inputs = encode_string(value);
+        let (decoded, consumed) = decode_string(&inputs)?;
+        assert_eq!(decoded, value);
+        assert_eq!(consumed, inputs.len(), "{:?}", value);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_empty_string_is_only_a_length() -> Result<()> {
+    assert_eq!(encode_string(""), vec![0]);
+    assert_eq!(decode_string(&[0, 7, 8])?, (String::new(), 1));
+    Ok(())
+}
+
+#[test]
+fn test_word_aligned_lengths_use_no_padding_word() -> Result<()> {
+    for length in [16usize, 32, 64] {
+        let bytes = vec![0xab; length];
+        let inputs = encode_bytes(&bytes);
+        assert_eq!(inputs.len(), 1 + length / 16);
+        assert_eq!(inputs[0], length as u128);
+        assert_eq!(decode_bytes(&inputs)?, (bytes, 1 + length / 16));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_decode_ignores_trailing_inputs() -> Result<()> {
+    let mut inputs = encode_string("hello");
+    inputs.extend([1, 2, 3]);
+    assert_eq!(decode_string(&inputs)?, ("hello".to_string(), 2));
+    Ok(())
+}
+
+#[test]
+fn test_non_zero_padding_is_rejected() {
+    let mut inputs = encode_string("hello");
+    inputs[1] |= 1u128 << 120;
+    assert!(decode_string(&inputs).is_err());
+
+    // Only the unused bytes of the last word are padding
+    let mut inputs = encode_bytes(&[1; 17]);
+    inputs[2] |= 0xff00;
+    assert!(decode_bytes(&inputs).is_err());
+}
+
+#[test]
+fn test_length_longer_than_the_inputs_is_rejected() {
+    let inputs = encode_string("a string spanning three words....");
+    assert!(decode_string(&inputs[..inputs.len() - 1]).is_err());
+    assert!(decode_bytes(&[17, 0]).is_err());
+    assert!(decode_bytes(&[u128::MAX]).is_err());
+    assert!(decode_bytes(&[]).is_err());
+}
+
+#[test]
+fn test_invalid_utf8_is_rejected() {
+    let inputs = encode_bytes(&[0xff, 0xfe]);
+    assert!(decode_bytes(&inputs).is_ok());
+    assert!(decode_string(&inputs).is_err());
+}
+
+#[test]
+fn test_reader_reads_in_order() -> Result<()> {
+    let mut inputs = vec![7];
+    inputs.extend(encode_string("name"));
+    inputs.push(9);
+
+    let mut reader = InputReader::new(&inputs);
+    assert_eq!(reader.next_u128()?, 7);
+    assert_eq!(reader.next_string()?, "name");
+    assert_eq!(reader.remaining(), &[9]);
+    assert_eq!(reader.next_u128()?, 9);
+    assert!(reader.is_empty());
+    assert!(reader.next_u128().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_next_u128_or_defaults_at_end_of_input() {
+    let mut reader = InputReader::new(&[5]);
+    assert_eq!(reader.next_u128_or(42), 5);
+    assert_eq!(reader.next_u128_or(42), 42);
+    assert_eq!(reader.next_u128_or(0), 0);
+    assert!(reader.is_empty());
+
+    let mut reader = InputReader::new(&[]);
+    assert_eq!(reader.next_u128_or(u128::MAX), u128::MAX);
+}
+
+#[test]
+fn test_skip_stays_in_bounds() -> Result<()> {
+    let mut reader = InputReader::new(&[1, 2, 3]);
+    reader.skip(2)?;
+    assert_eq!(reader.next_u128()?, 3);
+    assert!(reader.skip(1).is_err());
+    reader.skip(0)?;
+    Ok(())
+}
+
+#[test]
+fn test_trailing_skips_the_opcode_and_fixed_arguments() -> Result<()> {
+    // Opcode 12 followed by two fixed arguments and then the trailing input
+    let context_inputs = [12, 100, 200, 300];
+
+    assert_eq!(InputReader::trailing(&context_inputs, 0).remaining(), &[100, 200, 300]);
+    assert_eq!(InputReader::trailing(&context_inputs, 2).remaining(), &[300]);
+    assert!(InputReader::trailing(&context_inputs, 3).is_empty());
+
+    // Fixed arguments past the end of the inputs leave nothing to read
+    assert!(InputReader::trailing(&context_inputs, 10).is_empty());
+    assert!(InputReader::trailing(&[], 0).is_empty());
+
+    let mut reader = InputReader::trailing(&context_inputs, 2);
+    assert_eq!(reader.next_u128()?, 300);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+use orbitals_support::config::{alkane_detail, config_fields, digest_detail, ConfigChange, CONFIG_CHANGE_SIZE, NO_DETAIL};
+
+#[test]
+fn test_config_change_round_trips() -> Result<()> {
+    let change = ConfigChange {
+        height: 840_000,
+        field: config_fields::PAYMENT_ADDED,
+        value: u128::MAX,
+        detail: alkane_detail(2, 7),
+    };
+    let bytes = change.to_bytes();
+
+    assert_eq!(bytes.len(), CONFIG_CHANGE_SIZE);
+    assert_eq!(ConfigChange::from_bytes(&bytes)?, change);
+    Ok(())
+}
+
+#[test]
+fn test_config_change_rejects_wrong_size() {
+    let bytes = ConfigChange { height: 1, field: config_fields::PAUSED, value: 1, detail: NO_DETAIL }.to_bytes();
+
+    assert!(ConfigChange::from_bytes(&bytes[..79]).is_err());
+    assert!(ConfigChange::from_bytes(&[bytes.clone(), vec![0]].concat()).is_err());
+}
+
+#[test]
+fn test_alkane_detail_holds_the_id() {
+    let detail = alkane_detail(2, 7);
+
+    assert_eq!(u128::from_le_bytes(detail[0..16].try_into().unwrap()), 2);
+    assert_eq!(u128::from_le_bytes(detail[16..32].try_into().unwrap()), 7);
+}
+
+#[test]
+fn test_digest_detail_commits_to_the_encoding() {
+    assert_eq!(digest_detail(b"abc"), digest_detail(b"abc"));
+    assert_ne!(digest_detail(b"abc"), digest_detail(b"abd"));
+    assert_ne!(digest_detail(b""), NO_DETAIL);
+}
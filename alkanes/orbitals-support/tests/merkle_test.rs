@@ -0,0 +1,66 @@
+use anyhow::Result;
+use orbitals_support::codec::InputReader;
+use orbitals_support::merkle::{hash_to_inputs, leaf_hash, proof, read_proof, root, verify, MerkleHash};
+
+fn buyer(n: u8) -> [u8; 32] {
+    [n; 32]
+}
+
+fn leaves(count: u8) -> Vec<MerkleHash> {
+    (0..count).map(|n| leaf_hash(&buyer(n), n as u128 + 1)).collect()
+}
+
+#[test]
+fn test_every_leaf_proves_membership() {
+    for count in 1..=9 {
+        let leaves = leaves(count);
+        let root = root(&leaves).unwrap();
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = proof(&leaves, index).unwrap();
+            assert!(verify(&root, leaf, &proof), "leaf {} of {}", index, count);
+        }
+    }
+}
+
+#[test]
+fn test_single_leaf_is_the_root() {
+    let leaves = leaves(1);
+
+    assert_eq!(root(&leaves), Some(leaves[0]));
+    assert_eq!(proof(&leaves, 0), Some(vec![]));
+}
+
+#[test]
+fn test_wrong_quantity_fails() {
+    let leaves = leaves(4);
+    let root = root(&leaves).unwrap();
+    let proof = proof(&leaves, 2).unwrap();
+
+    assert!(verify(&root, &leaf_hash(&buyer(2), 3), &proof));
+    assert!(!verify(&root, &leaf_hash(&buyer(2), 4), &proof));
+    assert!(!verify(&root, &leaf_hash(&buyer(9), 3), &proof));
+}
+
+#[test]
+fn test_read_proof_from_inputs() -> Result<()> {
+    let leaves = leaves(5);
+    let expected = proof(&leaves, 3).unwrap();
+
+    let mut inputs = vec![expected.len() as u128];
+    for hash in &expected {
+        inputs.extend(hash_to_inputs(hash));
+    }
+    inputs.push(42);
+
+    let mut reader = InputReader::new(&inputs);
+    assert_eq!(read_proof(&mut reader)?, expected);
+    assert_eq!(reader.next_u128()?, 42);
+    Ok(())
+}
+
+#[test]
+fn test_read_proof_rejects_long_and_short_proofs() {
+    assert!(read_proof(&mut InputReader::new(&[33])).is_err());
+    assert!(read_proof(&mut InputReader::new(&[2, 1, 2, 3])).is_err());
+}
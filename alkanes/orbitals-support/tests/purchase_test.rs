@@ -0,0 +1,34 @@
+use orbitals_support::purchase::mint_allowance;
+
+#[test]
+fn test_no_caps_allow_any_quantity() {
+    assert_eq!(mint_allowance(0, 0, None), u128::MAX);
+    assert_eq!(mint_allowance(0, 0, Some(1_000)), u128::MAX);
+}
+
+#[test]
+fn test_per_purchase_cap() {
+    assert_eq!(mint_allowance(5, 0, None), 5);
+    assert_eq!(mint_allowance(5, 0, Some(40)), 5);
+}
+
+#[test]
+fn test_per_buyer_cap_counts_what_was_minted() {
+    assert_eq!(mint_allowance(0, 10, Some(0)), 10);
+    assert_eq!(mint_allowance(0, 10, Some(7)), 3);
+    assert_eq!(mint_allowance(0, 10, Some(10)), 0);
+    // Buyers past the cap, for example after it was lowered, get nothing rather than an underflow
+    assert_eq!(mint_allowance(0, 10, Some(12)), 0);
+}
+
+#[test]
+fn test_tighter_cap_wins() {
+    assert_eq!(mint_allowance(5, 10, Some(2)), 5);
+    assert_eq!(mint_allowance(5, 10, Some(8)), 2);
+}
+
+#[test]
+fn test_per_buyer_cap_needs_a_known_buyer() {
+    assert_eq!(mint_allowance(5, 10, None), 5);
+    assert_eq!(mint_allowance(0, 10, None), u128::MAX);
+}
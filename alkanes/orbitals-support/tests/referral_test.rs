@@ -0,0 +1,59 @@
+use anyhow::Result;
+use bitcoin::absolute::LockTime;
+use bitcoin::script::Builder;
+use bitcoin::opcodes::all::OP_RETURN;
+use bitcoin::transaction::Version;
+use bitcoin::{Amount, ScriptBuf, Transaction, TxOut};
+use orbitals_support::buyer::{buyer_key, buyer_key_to_inputs};
+use orbitals_support::codec::InputReader;
+use orbitals_support::purchase::{purchase_tags, PurchaseOptions};
+use orbitals_support::referral::{referral_output_script, referrer_script};
+
+fn referrer() -> ScriptBuf {
+    let mut script = vec![0x51, 0x20];
+    script.extend_from_slice(&[9u8; 32]);
+    ScriptBuf::from_bytes(script)
+}
+
+fn transaction(scripts: Vec<ScriptBuf>) -> Transaction {
+    Transaction {
+        version: Version::TWO,
+        lock_time: LockTime::ZERO,
+        input: vec![],
+        output: scripts
+            .into_iter()
+            .map(|script_pubkey| TxOut { value: Amount::ZERO, script_pubkey })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_referrer_round_trips_through_op_return() {
+    let tx = transaction(vec![referrer(), referral_output_script(&referrer())]);
+
+    assert_eq!(referrer_script(&tx), Some(referrer()));
+}
+
+#[test]
+fn test_other_op_returns_are_ignored() {
+    let runestone = Builder::new().push_opcode(OP_RETURN).push_int(13).push_slice([1u8, 2, 3]).into_script();
+    let unrelated = Builder::new().push_opcode(OP_RETURN).push_slice(b"hello").push_slice([1u8; 4]).into_script();
+
+    assert_eq!(referrer_script(&transaction(vec![runestone.clone(), unrelated.clone()])), None);
+
+    let tx = transaction(vec![runestone, unrelated, referral_output_script(&referrer())]);
+    assert_eq!(referrer_script(&tx), Some(referrer()));
+}
+
+#[test]
+fn test_referrer_purchase_section() -> Result<()> {
+    let key = buyer_key(&referrer());
+    let [low, high] = buyer_key_to_inputs(&key);
+    let inputs = vec![purchase_tags::REFERRER, low, high];
+
+    let options = PurchaseOptions::read(&mut InputReader::new(&inputs))?;
+
+    assert_eq!(options.referrer, Some(key));
+    assert!(options.voucher.is_none());
+    Ok(())
+}
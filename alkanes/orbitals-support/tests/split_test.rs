@@ -0,0 +1,47 @@
+use orbitals_support::split::{share_of, split_shares, validate_shares, BPS_DENOMINATOR, MAX_BENEFICIARIES};
+
+#[test]
+fn test_split_adds_up_to_the_amount() {
+    let shares = [5000, 3000, 2000];
+    for amount in [0u128, 1, 7, 9_999, 10_000, 123_457, u128::MAX] {
+        let parts = split_shares(amount, &shares, 0);
+        assert_eq!(parts.iter().sum::<u128>(), amount, "amount {}", amount);
+    }
+}
+
+#[test]
+fn test_split_gives_dust_to_the_named_share() {
+    // 10 / 3 leaves 1 unit of dust
+    let parts = split_shares(10, &[3334, 3333, 3333], 2);
+    assert_eq!(parts, vec![3, 3, 4]);
+
+    let parts = split_shares(10, &[3334, 3333, 3333], 0);
+    assert_eq!(parts, vec![4, 3, 3]);
+}
+
+#[test]
+fn test_share_of_large_amounts_does_not_overflow() {
+    assert_eq!(share_of(u128::MAX, BPS_DENOMINATOR), u128::MAX);
+    assert_eq!(share_of(u128::MAX, 5000), u128::MAX / 2);
+    assert_eq!(share_of(20_000, 2500), 5000);
+}
+
+#[test]
+fn test_validate_shares() {
+    assert!(validate_shares(&[BPS_DENOMINATOR], 0).is_ok());
+    assert!(validate_shares(&[6000, 4000], 1).is_ok());
+
+    // Must add up to the whole
+    assert!(validate_shares(&[6000, 3000], 0).is_err());
+    assert!(validate_shares(&[6000, 5000], 0).is_err());
+    // Dust party must be one of the shares
+    assert!(validate_shares(&[6000, 4000], 2).is_err());
+    // Nothing to split between
+    assert!(validate_shares(&[], 0).is_err());
+    // Too many beneficiaries
+    let mut shares = vec![0; MAX_BENEFICIARIES + 1];
+    shares[0] = BPS_DENOMINATOR;
+    assert!(validate_shares(&shares, 0).is_err());
+    // Overflowing shares
+    assert!(validate_shares(&[u128::MAX, 1], 0).is_err());
+}
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use bitcoin::hashes::{sha256, Hash};
+use orbitals_support::codec::{encode_string, InputReader};
+use orbitals_support::merkle::hash_to_inputs;
+use orbitals_support::purchase::{purchase_tags, PurchaseOptions};
+use orbitals_support::terms::{accepted_version, encode_history, terms_kinds, TermsVersion};
+
+fn read(inputs: &[u128]) -> Result<TermsVersion> {
+    TermsVersion::read(&mut InputReader::new(inputs), 840_000)
+}
+
+#[test]
+fn test_text_terms_commit_to_their_hash() -> Result<()> {
+    let mut inputs = vec![terms_kinds::TEXT];
+    inputs.extend(encode_string("No refunds."));
+
+    let version = read(&inputs)?;
+
+    assert_eq!(version.hash, sha256::Hash::hash(b"No refunds.").to_byte_array());
+    assert_eq!(version.body, "No refunds.");
+    assert_eq!(version.height, 840_000);
+    Ok(())
+}
+
+#[test]
+fn test_hash_committed_terms_keep_the_uri() -> Result<()> {
+    let hash = [7u8; 32];
+    let mut inputs = vec![terms_kinds::HASH_URI];
+    inputs.extend(hash_to_inputs(&hash));
+    inputs.extend(encode_string("ipfs://terms"));
+
+    let version = read(&inputs)?;
+
+    assert_eq!(version.hash, hash);
+    assert_eq!(version.kind, terms_kinds::HASH_URI);
+    assert_eq!(version.body, "ipfs://terms");
+    Ok(())
+}
+
+#[test]
+fn test_invalid_terms_are_rejected() {
+    assert!(read(&[terms_kinds::TEXT, 0]).is_err());
+    assert!(read(&[terms_kinds::HASH_URI, 0, 0, 1, 0x61]).is_err());
+    assert!(read(&[9]).is_err());
+}
+
+#[test]
+fn test_terms_version_round_trips() -> Result<()> {
+    let version = TermsVersion::from_text("Sold as-is.", 12);
+
+    assert_eq!(TermsVersion::from_bytes(&version.to_bytes())?, version);
+    assert!(TermsVersion::from_bytes(&[0u8; 63]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_history_encoding() {
+    let history = vec![TermsVersion::from_text("v1", 1), TermsVersion::from_text("version 2", 5)];
+
+    let bytes = encode_history(&history);
+
+    // Count, then a 64-byte header, a length and the body per version
+    assert_eq!(bytes.len(), 16 + (80 + 2) + (80 + 9));
+    assert_eq!(u128::from_le_bytes(bytes[0..16].try_into().unwrap()), 2);
+}
+
+#[test]
+fn test_acceptance_must_match_the_current_version() -> Result<()> {
+    let history = vec![TermsVersion::from_text("v1", 1), TermsVersion::from_text("v2", 5)];
+
+    assert_eq!(accepted_version(&history, Some(&history[1].hash), false)?, Some(2));
+    assert!(accepted_version(&history, Some(&history[0].hash), false).is_err());
+    assert_eq!(accepted_version(&history, None, false)?, None);
+    assert!(accepted_version(&history, None, true).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_terms_purchase_section() -> Result<()> {
+    let hash = TermsVersion::from_text("v1", 1).hash;
+    let mut inputs = vec![purchase_tags::TERMS];
+    inputs.extend(hash_to_inputs(&hash));
+
+    let options = PurchaseOptions::read(&mut InputReader::new(&inputs))?;
+
+    assert_eq!(options.terms, Some(hash));
+    Ok(())
+}
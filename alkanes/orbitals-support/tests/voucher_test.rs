@@ -0,0 +1,85 @@
+use alkanes_support::id::AlkaneId;
+use anyhow::Result;
+use bitcoin::secp256k1::{Keypair, Message, Secp256k1};
+use orbitals_support::codec::InputReader;
+use orbitals_support::purchase::{purchase_tags, PurchaseOptions};
+use orbitals_support::voucher::Voucher;
+
+const SALE: AlkaneId = AlkaneId { block: 2, tx: 100 };
+const BUYER: [u8; 32] = [5u8; 32];
+
+/// Sign a voucher the way the sale admin would off-chain
+fn signed_voucher(keypair: &Keypair, quantity: u128, expiry: u128, nonce: u128) -> Voucher {
+    let mut voucher = Voucher { quantity, price: 1000, expiry, nonce, signature: [0u8; 64] };
+    let message = Message::from_digest(voucher.digest(&SALE, &BUYER));
+    let signature = Secp256k1::new().sign_schnorr_no_aux_rand(&message, keypair);
+    voucher.signature = signature.serialize();
+    voucher
+}
+
+fn admin() -> (Keypair, [u8; 32]) {
+    let keypair = Keypair::from_seckey_slice(&Secp256k1::new(), &[7u8; 32]).unwrap();
+    let signer = keypair.x_only_public_key().0.serialize();
+    (keypair, signer)
+}
+
+#[test]
+fn test_signed_voucher_verifies() -> Result<()> {
+    let (keypair, signer) = admin();
+    let voucher = signed_voucher(&keypair, 3, 900, 1);
+
+    voucher.verify(&SALE, &BUYER, &signer, 900)
+}
+
+#[test]
+fn test_voucher_is_bound_to_buyer_and_sale() {
+    let (keypair, signer) = admin();
+    let voucher = signed_voucher(&keypair, 3, 0, 1);
+
+    assert!(voucher.verify(&SALE, &[6u8; 32], &signer, 0).is_err());
+    assert!(voucher.verify(&AlkaneId { block: 2, tx: 101 }, &BUYER, &signer, 0).is_err());
+}
+
+#[test]
+fn test_tampered_voucher_fails() {
+    let (keypair, signer) = admin();
+    let voucher = Voucher { quantity: 30, ..signed_voucher(&keypair, 3, 0, 1) };
+
+    assert!(voucher.verify(&SALE, &BUYER, &signer, 0).is_err());
+}
+
+#[test]
+fn test_other_signer_fails() {
+    let (keypair, _) = admin();
+    let other = Keypair::from_seckey_slice(&Secp256k1::new(), &[8u8; 32]).unwrap();
+    let voucher = signed_voucher(&keypair, 3, 0, 1);
+
+    assert!(voucher.verify(&SALE, &BUYER, &other.x_only_public_key().0.serialize(), 0).is_err());
+}
+
+#[test]
+fn test_expired_voucher_fails() {
+    let (keypair, signer) = admin();
+    let voucher = signed_voucher(&keypair, 3, 900, 1);
+
+    assert!(voucher.verify(&SALE, &BUYER, &signer, 901).is_err());
+}
+
+#[test]
+fn test_voucher_in_purchase_sections() -> Result<()> {
+    let (keypair, signer) = admin();
+    let voucher = signed_voucher(&keypair, 3, 0, 9);
+
+    let mut inputs = vec![purchase_tags::VOUCHER];
+    inputs.extend(voucher.to_inputs());
+    let options = PurchaseOptions::read(&mut InputReader::new(&inputs))?;
+
+    assert_eq!(options.voucher.as_ref(), Some(&voucher));
+    assert!(options.merkle_proof.is_none());
+    options.voucher.unwrap().verify(&SALE, &BUYER, &signer, 0)
+}
+
+#[test]
+fn test_unknown_purchase_section_fails() {
+    assert!(PurchaseOptions::read(&mut InputReader::new(&[9, 1, 2])).is_err());
+}
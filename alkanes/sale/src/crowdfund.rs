@@ -0,0 +1,60 @@
+use anyhow::{anyhow, Result};
+
+/// States a crowdfund moves through
+pub mod crowdfund_states {
+    /// Taking purchases; receipts cannot be redeemed yet
+    pub const OPEN: u128 = 0;
+    /// The minimum was reached; receipts redeem for orbitals
+    pub const SUCCEEDED: u128 = 1;
+    /// The deadline passed short of the minimum; receipts redeem for refunds
+    pub const FAILED: u128 = 2;
+}
+
+/// A minimum raise that purchases are held in escrow for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crowdfund {
+    /// Number of orbitals that must be sold for the crowdfund to succeed
+    pub minimum: u128,
+    /// Block height purchases close at (exclusive)
+    pub deadline: u128,
+}
+
+impl Crowdfund {
+    /// Get the state of the crowdfund from the number sold and the block height
+    ///
+    /// The crowdfund succeeds as soon as the minimum is sold, even before the deadline
+    pub fn state(&self, sold: u128, height: u128) -> u128 {
+        if sold >= self.minimum {
+            crowdfund_states::SUCCEEDED
+        } else if height >= self.deadline {
+            crowdfund_states::FAILED
+        } else {
+            crowdfund_states::OPEN
+        }
+    }
+
+    /// Check if purchases are accepted at the block height
+    pub fn accepts_purchases(&self, height: u128) -> bool {
+        height < self.deadline
+    }
+}
+
+/// Get the part of the escrow owed for `receipts` of the `outstanding` receipts, rounding down
+///
+/// Redeeming the last outstanding receipts takes whatever is left, so the escrow empties exactly
+pub fn refund_share(escrow: u128, receipts: u128, outstanding: u128) -> Result<u128> {
+    if receipts > outstanding {
+        return Err(anyhow!("{} receipts redeemed but only {} are outstanding", receipts, outstanding));
+    }
+    if receipts == outstanding {
+        return Ok(escrow);
+    }
+
+    // Divide before multiplying where possible so large escrows cannot overflow
+    let per_receipt = escrow / outstanding;
+    let remainder = escrow % outstanding;
+    per_receipt
+        .checked_mul(receipts)
+        .and_then(|share| remainder.checked_mul(receipts).map(|extra| share + extra / outstanding))
+        .ok_or_else(|| anyhow!("refund overflow"))
+}
@@ -5,7 +5,7 @@ use alkanes_runtime::{
     println,
     stdio::{stdout, Write},
 };
-use alkanes_runtime::{runtime::AlkaneResponder, storage::StoragePointer};
+use alkanes_runtime::{auth::AuthenticatedResponder, runtime::AlkaneResponder, storage::StoragePointer};
 use alkanes_support::{parcel::AlkaneTransfer, response::CallResponse};
 use anyhow::{anyhow, Result};
 use metashrew_support::compat::to_arraybuffer_layout;
@@ -14,16 +14,130 @@ use alkanes_support::utils::overflow_error;
 use alkanes_support::id::AlkaneId;
 use alkanes_support::parcel::AlkaneTransferParcel;
 use alkanes_support::cellpack::Cellpack;
+use orbitals_support::buyer::{buyer_key_from_inputs, caller_buyer_key, BuyerKey};
+use orbitals_support::codec::InputReader;
+use orbitals_support::config::{alkane_detail, config_fields, digest_detail, ConfigChange, NO_DETAIL};
+use orbitals_support::merkle::{self, MerkleHash};
+use orbitals_support::purchase::{mint_allowance, PurchaseOptions};
+use orbitals_support::split::{share_of, split_shares, validate_shares, BPS_DENOMINATOR, MAX_BENEFICIARIES};
+use orbitals_support::terms::{accepted_version, encode_history, TermsVersion};
+use orbitals_support::voucher::Voucher;
+use bitcoin::secp256k1::XOnlyPublicKey;
 use std::sync::Arc;
 
+// Pricing modes and price calculations
+pub mod pricing;
+
+// Phase schedules with block height windows
+pub mod phases;
+
+// Minimum-raise escrow with receipts
+pub mod crowdfund;
+
+use crowdfund::{crowdfund_states, refund_share, Crowdfund};
+use phases::{active_phase, gates, validate_schedule, Phase, PHASE_INPUTS};
+use pricing::{from_base_units, pricing_modes, to_base_units, BondingCurve, DutchAuction};
+
 /// Sale alkane for selling orbital instances
 #[derive(Default)]
 pub struct Sale(());
 
+/// Tags for the optional configuration sections that follow the fixed Initialize arguments
+pub mod init_tags {
+    /// Dutch auction pricing: start price, floor price, start height, decay per step,
+    /// blocks per step and a rebate flag (1 to rebate early buyers down to the clearing price)
+    pub const DUTCH_AUCTION: u128 = 1;
+    /// Bonding curve pricing on the sold count: a curve kind from `pricing::curve_kinds`
+    /// followed by its parameters
+    pub const BONDING_CURVE: u128 = 2;
+    /// Phase schedule: the number of phases followed by each phase's start height, end height,
+    /// price, limit, gate and gate alkane block and tx (see `phases::Phase`)
+    pub const PHASES: u128 = 3;
+    /// Mint caps: the maximum per purchase and the maximum per buyer (0 for no cap)
+    pub const CAPS: u128 = 4;
+    /// Voucher signer: the admin's BIP-340 x-only public key as two u128 inputs
+    /// (its little-endian halves)
+    pub const VOUCHER_SIGNER: u128 = 5;
+    /// Beneficiaries: the number of beneficiaries, the index of the one that receives rounding
+    /// dust, then each beneficiary's key as two u128 inputs and its share in basis points
+    pub const BENEFICIARIES: u128 = 6;
+    /// Referrals: the referrer's cut of each referred purchase in basis points
+    pub const REFERRALS: u128 = 7;
+    /// Crowdfund: the minimum number of orbitals to sell and the block height purchases close at
+    pub const CROWDFUND: u128 = 8;
+    /// Terms of service: 1 to require buyers to accept them with each purchase (0 otherwise),
+    /// then a kind from `orbitals_support::terms::terms_kinds` and the terms
+    /// (see `TermsVersion::read`)
+    pub const TERMS: u128 = 9;
+}
+
+/// A purchase admitted to the active phase
+pub struct Admission {
+    /// Index of the phase in the schedule
+    pub index: usize,
+    /// The active phase
+    pub phase: Phase,
+    /// Buyer proven against the phase's Merkle allowlist
+    pub merkle_buyer: Option<BuyerKey>,
+    /// Most orbitals the phase gate lets the buyer take
+    pub allowance: u128,
+}
+
+/// A payment alkane the sale accepts and its price per orbital
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaymentEntry {
+    /// Payment alkane ID
+    pub id: AlkaneId,
+    /// Price per orbital in the payment alkane's units
+    pub price: u128,
+}
+
+/// A party that receives a share of the sale proceeds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Beneficiary {
+    /// SHA-256 hash of the output script the beneficiary claims to (see `orbitals_support::buyer`)
+    pub key: BuyerKey,
+    /// Share of the proceeds in basis points
+    pub bps: u128,
+}
+
+/// Find the accepted payment alkane a purchase pays with
+///
+/// This is the first incoming transfer of an alkane in the price table
+pub fn select_payment(incoming_alkanes: &AlkaneTransferParcel, payments: &[PaymentEntry]) -> Option<PaymentEntry> {
+    incoming_alkanes.0.iter()
+        .find_map(|transfer| payments.iter().find(|entry| entry.id == transfer.id))
+        .copied()
+}
+
+/// Split incoming alkanes into the total paid in the payment alkane and the transfers to refund
+///
+/// Every transfer of the payment alkane counts towards the payment, and all other
+/// transfers are returned unchanged so the sale never keeps alkanes it did not ask for
+pub fn split_payment(
+    incoming_alkanes: &AlkaneTransferParcel,
+    payment_id: &AlkaneId
+) -> Result<(u128, Vec<AlkaneTransfer>)> {
+    let mut payment_amount = 0u128;
+    let mut refunds = Vec::new();
+
+    for transfer in &incoming_alkanes.0 {
+        if transfer.id == *payment_id {
+            payment_amount = overflow_error(payment_amount.checked_add(transfer.value))
+                .map_err(|_| anyhow!("payment amount overflow"))?;
+        } else {
+            refunds.push(transfer.clone());
+        }
+    }
+
+    Ok((payment_amount, refunds))
+}
+
 /// Message enum for opcode-based dispatch
 #[derive(MessageDispatch)]
 enum SaleMessage {
     /// Initialize the sale
+    /// Followed by optional configuration sections, each starting with a tag from `init_tags`
     #[opcode(0)]
     Initialize {
         /// Collection alkane block
@@ -40,21 +154,155 @@ enum SaleMessage {
         limit: u128,
     },
 
-    /// Purchase an orbital
+    /// Add buyers to a phase allowlist (requires the auth token)
+    /// Followed by the buyer keys, each as two u128 inputs (see `orbitals_support::buyer`)
+    #[opcode(10)]
+    AllowBuyers {
+        /// Index of the phase in the schedule
+        phase: u128,
+    },
+
+    /// Remove buyers from a phase allowlist (requires the auth token)
+    /// Followed by the buyer keys, each as two u128 inputs
+    #[opcode(11)]
+    DisallowBuyers {
+        /// Index of the phase in the schedule
+        phase: u128,
+    },
+
+    /// Set the Merkle allowlist root for a phase (requires the auth token)
+    #[opcode(12)]
+    SetMerkleRoot {
+        /// Index of the phase in the schedule
+        phase: u128,
+        /// Low half of the root
+        root_low: u128,
+        /// High half of the root
+        root_high: u128,
+    },
+
+    /// Add a payment alkane to the price table or change its price (requires the auth token)
+    /// The base payment alkane's price is changed with `UpdatePrice`
+    #[opcode(13)]
+    AddPayment {
+        /// Payment alkane block
+        block: u128,
+        /// Payment alkane tx
+        tx: u128,
+        /// Price per orbital in the payment alkane's units
+        price: u128,
+    },
+
+    /// Remove a payment alkane from the price table (requires the auth token)
+    #[opcode(14)]
+    RemovePayment {
+        /// Payment alkane block
+        block: u128,
+        /// Payment alkane tx
+        tx: u128,
+    },
+
+    /// Change the fixed price per orbital in the base payment alkane (requires the auth token)
+    #[opcode(15)]
+    UpdatePrice {
+        /// New price per orbital
+        price: u128,
+    },
+
+    /// Change the maximum number of orbitals that can be sold (requires the auth token)
+    /// Not allowed while a Dutch auction offers rebates
+    #[opcode(16)]
+    UpdateLimit {
+        /// New limit (0 for no limit)
+        limit: u128,
+    },
+
+    /// Replace the beneficiaries (requires the auth token)
+    /// Followed by the same inputs as the Initialize beneficiaries section; a count of 0
+    /// removes them so the owner withdraws the proceeds again
+    #[opcode(17)]
+    UpdateBeneficiaries,
+
+    /// Publish a new version of the terms of service (requires the auth token)
+    /// Followed by a kind from `orbitals_support::terms::terms_kinds` and the terms; only
+    /// allowed while purchases are paused or before a Dutch auction or phase schedule starts
+    #[opcode(18)]
+    UpdateTerms,
+
+    /// Pause or resume purchases (requires the auth token)
+    #[opcode(19)]
+    SetPaused {
+        /// 1 to pause purchases, 0 to resume them
+        paused: u128,
+    },
+
+    /// Withdraw base payment proceeds to the caller (requires the auth token)
+    /// When the sale has beneficiaries the amount is split between their balances instead
+    #[opcode(20)]
+    Withdraw {
+        /// Amount of the payment alkane to withdraw (0 for the whole balance)
+        amount: u128,
+    },
+
+    /// Withdraw the proceeds of any payment alkane to the caller (requires the auth token)
+    /// When the sale has beneficiaries the amount is split between their balances instead
+    #[opcode(21)]
+    WithdrawPayment {
+        /// Payment alkane block
+        block: u128,
+        /// Payment alkane tx
+        tx: u128,
+        /// Amount of the payment alkane to withdraw (0 for the whole balance)
+        amount: u128,
+    },
+
+    /// Claim the caller's beneficiary balance of a payment alkane
+    /// The beneficiary is identified by the output script the protostone pointer sends to
+    #[opcode(22)]
+    ClaimShare {
+        /// Payment alkane block
+        block: u128,
+        /// Payment alkane tx
+        tx: u128,
+    },
+
+    /// Claim the caller's referral balance of a payment alkane
+    /// The referrer is identified by the output script the protostone pointer sends to
+    #[opcode(23)]
+    ClaimReferral {
+        /// Payment alkane block
+        block: u128,
+        /// Payment alkane tx
+        tx: u128,
+    },
+
+    /// Purchase orbitals
+    /// Optionally followed by the quantity to buy (0 or omitted for as many as the payment covers)
+    /// and then tagged sections from `orbitals_support::purchase::purchase_tags`
     #[opcode(77)]
     Purchase,
 
+    /// Claim the Dutch auction rebate for the orbitals sent with the call
+    #[opcode(78)]
+    ClaimRebate,
+
+    /// Redeem the crowdfund receipts sent with the call for orbitals once the minimum is sold,
+    /// or for a refund once the deadline passes without it
+    #[opcode(79)]
+    RedeemReceipts,
+
     /// Get the collection alkane ID
     #[opcode(99)]
     #[returns(Vec<u8>)]
     GetCollectionAlkaneId,
 
-    /// Get the payment alkane ID
+    /// Get the base payment alkane ID
     #[opcode(100)]
     #[returns(Vec<u8>)]
     GetPaymentAlkaneId,
 
-    /// Get the price per orbital
+    /// Get the current price per orbital in the base payment alkane
+    /// (the price of the next orbital on a bonding curve)
     #[opcode(101)]
     #[returns(u128)]
     GetPrice,
@@ -69,10 +317,142 @@ enum SaleMessage {
     #[returns(u128)]
     GetSold,
 
-    /// Get the terms of service
+    /// Get the current terms of service: the text, or the URI of hash-committed terms
     #[opcode(104)]
     #[returns(String)]
     GetTermsOfService,
+
+    /// Get the base payment proceeds held by the sale
+    #[opcode(105)]
+    #[returns(u128)]
+    GetProceeds,
+
+    /// Get the Dutch auction state as u128 values: start price, floor price, start height,
+    /// decay, blocks per step, rebate flag, clearing price and settled flag
+    #[opcode(106)]
+    #[returns(Vec<u8>)]
+    GetAuction,
+
+    /// Get the cost of buying the next `count` orbitals at the current price
+    #[opcode(107)]
+    #[returns(u128)]
+    QuoteCost { count: u128 },
+
+    /// Get the active phase as u128 values: its index, start height, end height, price,
+    /// limit, gate, gate alkane block and tx, and the number sold in the phase
+    #[opcode(108)]
+    #[returns(Vec<u8>)]
+    GetPhase,
+
+    /// Get the phase schedule as u128 values: the number of phases followed by each
+    /// phase's start height, end height, price, limit, gate, gate alkane block and tx,
+    /// and the number sold in the phase
+    #[opcode(109)]
+    #[returns(Vec<u8>)]
+    GetPhases,
+
+    /// Get how many orbitals a buyer can still purchase in one call
+    #[opcode(110)]
+    #[returns(u128)]
+    GetAllowance {
+        /// Low half of the buyer key
+        key_low: u128,
+        /// High half of the buyer key
+        key_high: u128,
+    },
+
+    /// Get the Merkle allowlist root for a phase
+    #[opcode(111)]
+    #[returns(Vec<u8>)]
+    GetMerkleRoot {
+        /// Index of the phase in the schedule
+        phase: u128,
+    },
+
+    /// Get the x-only public key that signs mint vouchers
+    #[opcode(112)]
+    #[returns(Vec<u8>)]
+    GetVoucherSigner,
+
+    /// Check if a voucher nonce has been used (1 if used, 0 otherwise)
+    #[opcode(113)]
+    #[returns(u128)]
+    GetNonceUsed {
+        /// Voucher nonce
+        nonce: u128,
+    },
+
+    /// Get the accepted payment alkanes as u128 values: the number of entries followed by
+    /// each entry's block, tx, price per orbital and proceeds held
+    #[opcode(114)]
+    #[returns(Vec<u8>)]
+    GetPayments,
+
+    /// Get the beneficiaries as u128 values: the number of beneficiaries and the index of the
+    /// dust beneficiary followed by each beneficiary's key halves and share in basis points
+    #[opcode(115)]
+    #[returns(Vec<u8>)]
+    GetBeneficiaries,
+
+    /// Get a beneficiary's unclaimed balance of a payment alkane
+    #[opcode(116)]
+    #[returns(u128)]
+    GetShare {
+        /// Payment alkane block
+        block: u128,
+        /// Payment alkane tx
+        tx: u128,
+        /// Low half of the beneficiary key
+        key_low: u128,
+        /// High half of the beneficiary key
+        key_high: u128,
+    },
+
+    /// Get a referrer's stats as u128 values: the referral cut in basis points, the number of
+    /// referred purchases and orbitals, then the number of payment alkanes followed by each
+    /// one's block, tx and unclaimed referral balance
+    #[opcode(117)]
+    #[returns(Vec<u8>)]
+    GetReferralStats {
+        /// Low half of the referrer key
+        key_low: u128,
+        /// High half of the referrer key
+        key_high: u128,
+    },
+
+    /// Get the crowdfund as u128 values: the minimum, the deadline, the state from
+    /// `crowdfund::crowdfund_states`, the receipts outstanding and the payment held in escrow
+    #[opcode(118)]
+    #[returns(Vec<u8>)]
+    GetCrowdfund,
+
+    /// Get every configuration change: the number of changes as a u128 followed by each
+    /// change's block height, field from `orbitals_support::config::config_fields` and new
+    /// value as u128s and its 32-byte detail
+    #[opcode(119)]
+    #[returns(Vec<u8>)]
+    GetConfigLog,
+
+    /// Check if purchases are paused (1 if paused, 0 otherwise)
+    #[opcode(120)]
+    #[returns(u128)]
+    GetPaused,
+
+    /// Get every version of the terms of service: the number of versions followed by each
+    /// version's hash, block height, kind, body length as a u128 and body
+    #[opcode(121)]
+    #[returns(Vec<u8>)]
+    GetTermsHistory,
+
+    /// Get the terms version a buyer last accepted (0 if they have not accepted any)
+    #[opcode(122)]
+    #[returns(u128)]
+    GetTermsAccepted {
+        /// Low half of the buyer key
+        key_low: u128,
+        /// High half of the buyer key
+        key_high: u128,
+    },
 }
 
 impl Sale {
@@ -190,212 +570,1920 @@ impl Sale {
         Ok(())
     }
 
-    /// Get the terms of service
-    pub fn terms_of_service(&self) -> String {
-        "TERMS OF SERVICE AND SALE\n\n\
-        By using this service and purchasing non-fungible units of this digital asset, you agree to the following terms:\n\n\
-        1. The digital assets provided are sold as-is without any warranty, express or implied.\n\
-        2. The seller is not liable for any damages arising from the use of these digital assets.\n\
-        3. You assume all risks associated with the use of these digital assets.\n\
-        4. These digital assets are released without warranty to be used at your own risk.\n\
-        5. The seller makes no guarantees regarding the value, utility, or functionality of these digital assets.\n\
-        6. By completing a purchase, you acknowledge that you have read and agree to these terms.\n\n\
-        All sales are final. No refunds will be provided under any circumstances."
-            .to_string()
+    /// Get the pointer to the price table
+    pub fn payments_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/payments")
     }
 
-    /// Get the fuel amount for calls
-    pub fn fuel(&self) -> u64 {
-        // Default fuel value
-        1000000
+    /// Get the accepted payment alkanes and their prices
+    pub fn payments(&self) -> Vec<PaymentEntry> {
+        self.payments_pointer().get()
+            .chunks_exact(48)
+            .map(|chunk| PaymentEntry {
+                id: AlkaneId {
+                    block: u128::from_le_bytes(chunk[0..16].try_into().unwrap()),
+                    tx: u128::from_le_bytes(chunk[16..32].try_into().unwrap()),
+                },
+                price: u128::from_le_bytes(chunk[32..48].try_into().unwrap()),
+            })
+            .collect()
     }
 
-    /// Observe initialization to prevent multiple initializations
-    pub fn observe_initialization(&self) -> Result<()> {
-        let mut pointer = StoragePointer::from_keyword("/initialized");
-        if pointer.get().len() == 0 {
-            pointer.set_value::<u8>(0x01);
-            Ok(())
-        } else {
-            Err(anyhow!("already initialized"))
+    /// Set the accepted payment alkanes and their prices
+    pub fn set_payments(&self, payments: &[PaymentEntry]) {
+        let mut bytes = Vec::with_capacity(payments.len() * 48);
+        for entry in payments {
+            bytes.extend_from_slice(&entry.id.block.to_le_bytes());
+            bytes.extend_from_slice(&entry.id.tx.to_le_bytes());
+            bytes.extend_from_slice(&entry.price.to_le_bytes());
         }
+
+        self.payments_pointer().set(Arc::new(bytes));
     }
 
-    /// Calculate the number of orbitals that can be purchased with the given payment amount
-    pub fn calculate_purchase_count(&self, payment_amount: u128) -> (u128, u128) {
-        let price = self.price();
-        if price == 0 {
-            return (0, payment_amount);
+    /// Convert an amount of a payment alkane into base payment units, rounding down
+    pub fn to_base_units(&self, payment: &PaymentEntry, amount: u128) -> Result<u128> {
+        if payment.id == self.payment_alkane_id() {
+            return Ok(amount);
         }
-        
-        let count = payment_amount / price;
-        let change = payment_amount % price;
-        
-        (count, change)
+        to_base_units(amount, payment.price, self.price())
     }
 
-    /// Initialize the sale
-    fn initialize(
-        &self,
-        collection_alkane_block: u128,
-        collection_alkane_tx: u128,
-        payment_alkane_block: u128,
-        payment_alkane_tx: u128,
-        price: u128,
-        limit: u128
-    ) -> Result<CallResponse> {
-        let context = self.context()?;
-        let response = CallResponse::forward(&context.incoming_alkanes);
+    /// Convert an amount in base payment units into a payment alkane, rounding up
+    pub fn from_base_units(&self, payment: &PaymentEntry, amount: u128) -> Result<u128> {
+        if payment.id == self.payment_alkane_id() {
+            return Ok(amount);
+        }
+        from_base_units(amount, payment.price, self.price())
+    }
 
-        // Prevent multiple initializations
-        self.observe_initialization()?;
+    /// Get the pointer to the proceeds balance of a payment alkane
+    pub fn proceeds_pointer(&self, payment_id: &AlkaneId) -> StoragePointer {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&payment_id.block.to_le_bytes());
+        bytes.extend_from_slice(&payment_id.tx.to_le_bytes());
+        StoragePointer::from_keyword("/proceeds/").select(&bytes)
+    }
 
-        // Create the collection alkane ID
-        let collection_id = AlkaneId {
-            block: collection_alkane_block,
-            tx: collection_alkane_tx,
-        };
+    /// Get the base payment proceeds held by the sale and not yet withdrawn
+    pub fn proceeds(&self) -> u128 {
+        self.proceeds_of(&self.payment_alkane_id())
+    }
 
-        // Create the payment alkane ID
-        let payment_id = AlkaneId {
-            block: payment_alkane_block,
-            tx: payment_alkane_tx,
-        };
+    /// Get the proceeds of a payment alkane held by the sale and not yet withdrawn
+    pub fn proceeds_of(&self, payment_id: &AlkaneId) -> u128 {
+        self.proceeds_pointer(payment_id).get_value::<u128>()
+    }
 
-        // Set the collection alkane ID
-        self.set_collection_alkane_id(&collection_id);
+    /// Set the proceeds of a payment alkane
+    pub fn set_proceeds(&self, payment_id: &AlkaneId, proceeds: u128) {
+        self.proceeds_pointer(payment_id).set_value::<u128>(proceeds);
+    }
 
-        // Set the payment alkane ID
-        self.set_payment_alkane_id(&payment_id);
+    /// Add payment kept by a purchase to the proceeds
+    pub fn add_proceeds(&self, payment_id: &AlkaneId, amount: u128) -> Result<()> {
+        self.set_proceeds(payment_id, overflow_error(self.proceeds_of(payment_id).checked_add(amount))
+            .map_err(|_| anyhow!("proceeds overflow"))?);
+        Ok(())
+    }
 
-        // Set the price
-        self.set_price(price);
+    /// Get the pointer to the pricing mode
+    pub fn pricing_mode_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/pricing-mode")
+    }
 
-        // Set the limit
-        self.set_limit(limit);
+    /// Get the pricing mode (see `pricing::pricing_modes`)
+    pub fn pricing_mode(&self) -> u128 {
+        self.pricing_mode_pointer().get_value::<u128>()
+    }
 
-        // Initialize the sold counter
-        self.set_sold(0);
+    /// Set the pricing mode
+    pub fn set_pricing_mode(&self, mode: u128) {
+        self.pricing_mode_pointer().set_value::<u128>(mode);
+    }
 
-        Ok(response)
+    /// Get the pointer to the Dutch auction parameters
+    pub fn dutch_auction_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/dutch-auction")
     }
 
-    /// Purchase an orbital
-    fn purchase(&self) -> Result<CallResponse> {
-        let context = self.context()?;
-        let mut response = CallResponse::default();
+    /// Get the Dutch auction parameters if the sale uses Dutch auction pricing
+    pub fn dutch_auction(&self) -> Option<DutchAuction> {
+        if self.pricing_mode() != pricing_modes::DUTCH_AUCTION {
+            return None;
+        }
 
-        // Check if the sale has reached its limit
-        if self.sold() >= self.limit() {
-            return Err(anyhow!("Sale limit reached"));
+        let data = self.dutch_auction_pointer().get();
+        let bytes = data.as_ref();
+        if bytes.len() < 80 {
+            return None;
         }
 
-        // Get the payment alkane ID
-        let payment_id = self.payment_alkane_id();
+        let value = |i: usize| u128::from_le_bytes(bytes[i * 16..(i + 1) * 16].try_into().unwrap());
+        Some(DutchAuction {
+            start_price: value(0),
+            floor_price: value(1),
+            start_height: value(2),
+            decay: value(3),
+            step_blocks: value(4),
+        })
+    }
 
-        // Find the payment in the incoming alkanes
-        let mut payment_amount = 0u128;
-        for transfer in &context.incoming_alkanes.0 {
-            if transfer.id == payment_id {
-                payment_amount = transfer.value;
-                break;
-            }
-        }
+    /// Set the Dutch auction parameters and switch to Dutch auction pricing
+    pub fn set_dutch_auction(&self, auction: &DutchAuction) {
+        let mut bytes = Vec::with_capacity(80);
+        bytes.extend_from_slice(&auction.start_price.to_le_bytes());
+        bytes.extend_from_slice(&auction.floor_price.to_le_bytes());
+        bytes.extend_from_slice(&auction.start_height.to_le_bytes());
+        bytes.extend_from_slice(&auction.decay.to_le_bytes());
+        bytes.extend_from_slice(&auction.step_blocks.to_le_bytes());
+
+        self.dutch_auction_pointer().set(Arc::new(bytes));
+        self.set_pricing_mode(pricing_modes::DUTCH_AUCTION);
+    }
 
-        // Check if payment was provided
-        if payment_amount == 0 {
-            return Err(anyhow!("No payment provided"));
-        }
+    /// Get the pointer to the bonding curve
+    pub fn bonding_curve_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/bonding-curve")
+    }
 
-        // Calculate how many orbitals can be purchased and the change
-        let (purchase_count, change) = self.calculate_purchase_count(payment_amount);
-        
-        // Check if at least one orbital can be purchased
-        if purchase_count == 0 {
-            return Err(anyhow!("Insufficient payment"));
+    /// Get the bonding curve if the sale uses bonding curve pricing
+    pub fn bonding_curve(&self) -> Option<BondingCurve> {
+        if self.pricing_mode() != pricing_modes::BONDING_CURVE {
+            return None;
         }
 
-        // Check if the purchase would exceed the limit
-        let new_sold = self.sold() + purchase_count;
-        if new_sold > self.limit() {
-            return Err(anyhow!("Purchase would exceed sale limit"));
-        }
+        let inputs: Vec<u128> = self.bonding_curve_pointer().get()
+            .chunks_exact(16)
+            .map(|chunk| u128::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        BondingCurve::from_inputs(&inputs).ok().map(|(curve, _)| curve)
+    }
 
-        // Create a vector to store the purchased orbitals
-        let mut purchased_orbitals = Vec::new();
+    /// Set the bonding curve and switch to bonding curve pricing
+    pub fn set_bonding_curve(&self, curve: &BondingCurve) {
+        let bytes: Vec<u8> = curve.to_inputs()
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
 
-        // Purchase the orbitals
-        for _ in 0..purchase_count {
-            // Call the collection's CreateOrbital opcode
-            let collection_id = self.collection_alkane_id();
-            let cellpack = Cellpack {
-                target: collection_id,
-                inputs: vec![77], // CreateOrbital opcode
-            };
-            
-            let orbital_response = self.call(
-                &cellpack,
-                &AlkaneTransferParcel::default(),
-                self.fuel()
-            )?;
-            
-            // Extract the orbital instance ID from the response
-            // The response data format is: [block(16 bytes)][tx(16 bytes)][index(16 bytes)]
-            if orbital_response.data.len() < 48 {
-                return Err(anyhow!("Invalid response from collection"));
-            }
-            
-            let orbital_id = AlkaneId {
-                block: u128::from_le_bytes(orbital_response.data[0..16].try_into().unwrap()),
-                tx: u128::from_le_bytes(orbital_response.data[16..32].try_into().unwrap()),
-            };
-            
-            // Add the orbital to the purchased orbitals
-            purchased_orbitals.push(AlkaneTransfer {
-                id: orbital_id,
-                value: 1u128,
-            });
-        }
+        self.bonding_curve_pointer().set(Arc::new(bytes));
+        self.set_pricing_mode(pricing_modes::BONDING_CURVE);
+    }
 
-        // Update the sold counter
-        self.increment_sold(purchase_count)?;
+    /// Get the pointer to the phase schedule
+    pub fn phases_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/phases")
+    }
 
-        // Add the purchased orbitals to the response
-        response.alkanes.0.extend(purchased_orbitals);
+    /// Get the phase schedule (empty if the sale is always open)
+    pub fn phases(&self) -> Vec<Phase> {
+        let inputs: Vec<u128> = self.phases_pointer().get()
+            .chunks_exact(16)
+            .map(|chunk| u128::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
 
-        // Add change if any
-        if change > 0 {
-            response.alkanes.0.push(AlkaneTransfer {
-                id: payment_id,
-                value: change,
-            });
-        }
+        inputs.chunks_exact(PHASE_INPUTS)
+            .filter_map(|chunk| Phase::from_inputs(chunk).ok())
+            .collect()
+    }
 
-        Ok(response)
+    /// Set the phase schedule
+    pub fn set_phases(&self, phases: &[Phase]) {
+        let bytes: Vec<u8> = phases.iter()
+            .flat_map(|phase| phase.to_inputs())
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+
+        self.phases_pointer().set(Arc::new(bytes));
     }
 
-    /// Get the collection alkane ID
-    fn get_collection_alkane_id(&self) -> Result<CallResponse> {
-        let context = self.context()?;
-        let mut response = CallResponse::forward(&context.incoming_alkanes);
+    /// Get the phase open at the current block height and its index
+    pub fn current_phase(&self) -> Option<(usize, Phase)> {
+        active_phase(&self.phases(), self.current_height())
+    }
 
-        // Get the collection alkane ID
-        let collection_id = self.collection_alkane_id();
-        
-        // Serialize the AlkaneId to bytes
-        let mut bytes = Vec::with_capacity(32);
-        bytes.extend_from_slice(&collection_id.block.to_le_bytes());
-        bytes.extend_from_slice(&collection_id.tx.to_le_bytes());
-        
-        response.data = bytes;
+    /// Get the pointer to the number sold during a phase
+    pub fn phase_sold_pointer(&self, index: usize) -> StoragePointer {
+        StoragePointer::from_keyword("/phase-sold/").select(&(index as u128).to_le_bytes().to_vec())
+    }
 
-        Ok(response)
+    /// Get the number sold during a phase
+    pub fn phase_sold(&self, index: usize) -> u128 {
+        self.phase_sold_pointer(index).get_value::<u128>()
     }
 
-    /// Get the payment alkane ID
-    fn get_payment_alkane_id(&self) -> Result<CallResponse> {
-        let context = self.context()?;
-        let mut response = CallResponse::forward(&context.incoming_alkanes);
+    /// Increment the number sold during a phase
+    pub fn increment_phase_sold(&self, index: usize, count: u128) -> Result<()> {
+        let sold = overflow_error(self.phase_sold(index).checked_add(count))
+            .map_err(|_| anyhow!("phase sold counter overflow"))?;
+        self.phase_sold_pointer(index).set_value::<u128>(sold);
+        Ok(())
+    }
+
+    /// Get the pointer to a buyer's allowlist entry for a phase
+    pub fn allowlist_pointer(&self, index: usize, key: &BuyerKey) -> StoragePointer {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(&(index as u128).to_le_bytes());
+        bytes.extend_from_slice(key);
+        StoragePointer::from_keyword("/allowlist/").select(&bytes)
+    }
+
+    /// Check if a buyer is on a phase allowlist
+    pub fn is_allowed(&self, index: usize, key: &BuyerKey) -> bool {
+        self.allowlist_pointer(index, key).get_value::<u8>() == 1
+    }
+
+    /// Get the pointer to the Merkle allowlist root for a phase
+    pub fn merkle_root_pointer(&self, index: usize) -> StoragePointer {
+        StoragePointer::from_keyword("/merkle-root/").select(&(index as u128).to_le_bytes().to_vec())
+    }
+
+    /// Get the Merkle allowlist root for a phase, if one is set
+    pub fn merkle_root(&self, index: usize) -> Option<MerkleHash> {
+        self.merkle_root_pointer(index).get().as_ref().clone().try_into().ok()
+    }
+
+    /// Get the pointer to the number of orbitals a buyer has taken from a phase's Merkle allowlist
+    pub fn merkle_minted_pointer(&self, index: usize, key: &BuyerKey) -> StoragePointer {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(&(index as u128).to_le_bytes());
+        bytes.extend_from_slice(key);
+        StoragePointer::from_keyword("/merkle-minted/").select(&bytes)
+    }
+
+    /// Get the number of orbitals a buyer has taken from a phase's Merkle allowlist
+    pub fn merkle_minted(&self, index: usize, key: &BuyerKey) -> u128 {
+        self.merkle_minted_pointer(index, key).get_value::<u128>()
+    }
+
+    /// Increment the number of orbitals a buyer has taken from a phase's Merkle allowlist
+    pub fn increment_merkle_minted(&self, index: usize, key: &BuyerKey, count: u128) -> Result<()> {
+        let minted = overflow_error(self.merkle_minted(index, key).checked_add(count))
+            .map_err(|_| anyhow!("allowlist counter overflow"))?;
+        self.merkle_minted_pointer(index, key).set_value::<u128>(minted);
+        Ok(())
+    }
+
+    /// Get the pointer to the voucher signer
+    pub fn voucher_signer_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/voucher-signer")
+    }
+
+    /// Get the x-only public key that signs mint vouchers, if the sale accepts them
+    pub fn voucher_signer(&self) -> Option<[u8; 32]> {
+        self.voucher_signer_pointer().get().as_ref().clone().try_into().ok()
+    }
+
+    /// Get the pointer to the used flag for a voucher nonce
+    pub fn nonce_used_pointer(&self, nonce: u128) -> StoragePointer {
+        StoragePointer::from_keyword("/voucher-nonce/").select(&nonce.to_le_bytes().to_vec())
+    }
+
+    /// Check if a voucher nonce has been used
+    pub fn nonce_used(&self, nonce: u128) -> bool {
+        self.nonce_used_pointer(nonce).get_value::<u8>() == 1
+    }
+
+    /// Check a voucher for the buyer and spend its nonce
+    pub fn redeem_voucher(&self, voucher: &Voucher, buyer: &BuyerKey) -> Result<()> {
+        let context = self.context()?;
+        let signer = self.voucher_signer().ok_or_else(|| anyhow!("Sale does not accept vouchers"))?;
+
+        voucher.verify(&context.myself, buyer, &signer, self.current_height())?;
+        if self.nonce_used(voucher.nonce) {
+            return Err(anyhow!("Voucher nonce {} has already been used", voucher.nonce));
+        }
+        self.nonce_used_pointer(voucher.nonce).set_value::<u8>(1);
+        Ok(())
+    }
+
+    /// Get the pointer to the beneficiaries
+    pub fn beneficiaries_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/beneficiaries")
+    }
+
+    /// Get the beneficiaries the proceeds are split between (empty if the owner withdraws them)
+    pub fn beneficiaries(&self) -> Vec<Beneficiary> {
+        self.beneficiaries_pointer().get()
+            .chunks_exact(48)
+            .map(|chunk| Beneficiary {
+                key: chunk[0..32].try_into().unwrap(),
+                bps: u128::from_le_bytes(chunk[32..48].try_into().unwrap()),
+            })
+            .collect()
+    }
+
+    /// Set the beneficiaries the proceeds are split between
+    pub fn set_beneficiaries(&self, beneficiaries: &[Beneficiary]) {
+        let mut bytes = Vec::with_capacity(beneficiaries.len() * 48);
+        for beneficiary in beneficiaries {
+            bytes.extend_from_slice(&beneficiary.key);
+            bytes.extend_from_slice(&beneficiary.bps.to_le_bytes());
+        }
+
+        self.beneficiaries_pointer().set(Arc::new(bytes));
+    }
+
+    /// Get the pointer to the index of the beneficiary that receives rounding dust
+    pub fn dust_beneficiary_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/dust-beneficiary")
+    }
+
+    /// Get the index of the beneficiary that receives rounding dust
+    pub fn dust_beneficiary(&self) -> usize {
+        self.dust_beneficiary_pointer().get_value::<u128>() as usize
+    }
+
+    /// Get the pointer to a beneficiary's balance of a payment alkane
+    pub fn share_pointer(&self, payment_id: &AlkaneId, key: &BuyerKey) -> StoragePointer {
+        let mut bytes = Vec::with_capacity(64);
+        bytes.extend_from_slice(&payment_id.block.to_le_bytes());
+        bytes.extend_from_slice(&payment_id.tx.to_le_bytes());
+        bytes.extend_from_slice(key);
+        StoragePointer::from_keyword("/share/").select(&bytes)
+    }
+
+    /// Get a beneficiary's unclaimed balance of a payment alkane
+    pub fn share(&self, payment_id: &AlkaneId, key: &BuyerKey) -> u128 {
+        self.share_pointer(payment_id, key).get_value::<u128>()
+    }
+
+    /// Split an amount of a payment alkane between the beneficiaries' balances
+    pub fn credit_beneficiaries(&self, payment_id: &AlkaneId, amount: u128) -> Result<()> {
+        let beneficiaries = self.beneficiaries();
+        let shares: Vec<u128> = beneficiaries.iter().map(|beneficiary| beneficiary.bps).collect();
+        let parts = split_shares(amount, &shares, self.dust_beneficiary());
+
+        for (beneficiary, part) in beneficiaries.iter().zip(parts) {
+            let balance = overflow_error(self.share(payment_id, &beneficiary.key).checked_add(part))
+                .map_err(|_| anyhow!("beneficiary balance overflow"))?;
+            self.share_pointer(payment_id, &beneficiary.key).set_value::<u128>(balance);
+        }
+        Ok(())
+    }
+
+    /// Get the pointer to the crowdfund
+    pub fn crowdfund_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/crowdfund")
+    }
+
+    /// Get the crowdfund, if purchases are held in escrow
+    pub fn crowdfund(&self) -> Option<Crowdfund> {
+        let bytes = self.crowdfund_pointer().get();
+        if bytes.len() < 32 {
+            return None;
+        }
+
+        Some(Crowdfund {
+            minimum: u128::from_le_bytes(bytes[0..16].try_into().unwrap()),
+            deadline: u128::from_le_bytes(bytes[16..32].try_into().unwrap()),
+        })
+    }
+
+    /// Set the crowdfund
+    pub fn set_crowdfund(&self, crowdfund: &Crowdfund) {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&crowdfund.minimum.to_le_bytes());
+        bytes.extend_from_slice(&crowdfund.deadline.to_le_bytes());
+
+        self.crowdfund_pointer().set(Arc::new(bytes));
+    }
+
+    /// Get the pointer to the crowdfund escrow
+    pub fn escrow_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/escrow")
+    }
+
+    /// Get the base payment held in escrow for crowdfund receipts
+    pub fn escrow(&self) -> u128 {
+        self.escrow_pointer().get_value::<u128>()
+    }
+
+    /// Set the base payment held in escrow
+    pub fn set_escrow(&self, escrow: u128) {
+        self.escrow_pointer().set_value::<u128>(escrow);
+    }
+
+    /// Get the pointer to the number of crowdfund receipts outstanding
+    pub fn receipts_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/receipts")
+    }
+
+    /// Get the number of crowdfund receipts issued and not yet redeemed
+    pub fn receipts(&self) -> u128 {
+        self.receipts_pointer().get_value::<u128>()
+    }
+
+    /// Set the number of crowdfund receipts outstanding
+    pub fn set_receipts(&self, receipts: u128) {
+        self.receipts_pointer().set_value::<u128>(receipts);
+    }
+
+    /// Get the state of the crowdfund from `crowdfund::crowdfund_states`
+    pub fn crowdfund_state(&self, crowdfund: &Crowdfund) -> u128 {
+        crowdfund.state(self.sold(), self.current_height())
+    }
+
+    /// Release the escrow to the proceeds once the crowdfund has succeeded
+    pub fn settle_crowdfund(&self) -> Result<()> {
+        let succeeded = self.crowdfund()
+            .map_or(false, |crowdfund| self.crowdfund_state(&crowdfund) == crowdfund_states::SUCCEEDED);
+        let escrow = self.escrow();
+        if succeeded && escrow > 0 {
+            self.add_proceeds(&self.payment_alkane_id(), escrow)?;
+            self.set_escrow(0);
+        }
+        Ok(())
+    }
+
+    /// Get the pointer to the referral cut
+    pub fn referral_bps_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/referral-bps")
+    }
+
+    /// Get the referrer's cut of each referred purchase in basis points (0 for no referrals)
+    pub fn referral_bps(&self) -> u128 {
+        self.referral_bps_pointer().get_value::<u128>()
+    }
+
+    /// Get the pointer to a referrer's count of referred purchases
+    pub fn referral_purchases_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/referral-purchases/").select(&key.to_vec())
+    }
+
+    /// Get the pointer to a referrer's count of referred orbitals
+    pub fn referral_orbitals_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/referral-orbitals/").select(&key.to_vec())
+    }
+
+    /// Get the pointer to a referrer's balance of a payment alkane
+    pub fn referral_balance_pointer(&self, payment_id: &AlkaneId, key: &BuyerKey) -> StoragePointer {
+        let mut bytes = Vec::with_capacity(64);
+        bytes.extend_from_slice(&payment_id.block.to_le_bytes());
+        bytes.extend_from_slice(&payment_id.tx.to_le_bytes());
+        bytes.extend_from_slice(key);
+        StoragePointer::from_keyword("/referral-balance/").select(&bytes)
+    }
+
+    /// Get a referrer's unclaimed balance of a payment alkane
+    pub fn referral_balance(&self, payment_id: &AlkaneId, key: &BuyerKey) -> u128 {
+        self.referral_balance_pointer(payment_id, key).get_value::<u128>()
+    }
+
+    /// Credit a referrer with a referred purchase and their cut of it
+    pub fn credit_referral(&self, payment_id: &AlkaneId, key: &BuyerKey, orbitals: u128, cut: u128) -> Result<()> {
+        let add = |pointer: StoragePointer, amount: u128, what: &str| -> Result<()> {
+            let mut pointer = pointer;
+            let value = overflow_error(pointer.get_value::<u128>().checked_add(amount))
+                .map_err(|_| anyhow!("referral {} overflow", what))?;
+            pointer.set_value::<u128>(value);
+            Ok(())
+        };
+
+        add(self.referral_purchases_pointer(key), 1, "purchase counter")?;
+        add(self.referral_orbitals_pointer(key), orbitals, "orbital counter")?;
+        add(self.referral_balance_pointer(payment_id, key), cut, "balance")
+    }
+
+    /// Get the pointer to the maximum number of orbitals per purchase
+    pub fn max_per_purchase_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/max-per-purchase")
+    }
+
+    /// Get the maximum number of orbitals per purchase (0 for no cap)
+    pub fn max_per_purchase(&self) -> u128 {
+        self.max_per_purchase_pointer().get_value::<u128>()
+    }
+
+    /// Set the maximum number of orbitals per purchase
+    pub fn set_max_per_purchase(&self, max: u128) {
+        self.max_per_purchase_pointer().set_value::<u128>(max);
+    }
+
+    /// Get the pointer to the maximum number of orbitals per buyer
+    pub fn max_per_buyer_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/max-per-buyer")
+    }
+
+    /// Get the maximum number of orbitals per buyer (0 for no cap)
+    pub fn max_per_buyer(&self) -> u128 {
+        self.max_per_buyer_pointer().get_value::<u128>()
+    }
+
+    /// Set the maximum number of orbitals per buyer
+    pub fn set_max_per_buyer(&self, max: u128) {
+        self.max_per_buyer_pointer().set_value::<u128>(max);
+    }
+
+    /// Get the pointer to the number of orbitals a buyer has purchased
+    pub fn buyer_minted_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/buyer-minted/").select(&key.to_vec())
+    }
+
+    /// Get the number of orbitals a buyer has purchased
+    pub fn buyer_minted(&self, key: &BuyerKey) -> u128 {
+        self.buyer_minted_pointer(key).get_value::<u128>()
+    }
+
+    /// Increment the number of orbitals a buyer has purchased
+    pub fn increment_buyer_minted(&self, key: &BuyerKey, count: u128) -> Result<()> {
+        let minted = overflow_error(self.buyer_minted(key).checked_add(count))
+            .map_err(|_| anyhow!("buyer counter overflow"))?;
+        self.buyer_minted_pointer(key).set_value::<u128>(minted);
+        Ok(())
+    }
+
+    /// Get how many orbitals a buyer may take in one purchase under the mint caps
+    ///
+    /// The buyer is only needed when there is a per-buyer cap
+    pub fn purchase_allowance(&self, buyer: Option<&BuyerKey>) -> u128 {
+        mint_allowance(self.max_per_purchase(), self.max_per_buyer(), buyer.map(|key| self.buyer_minted(key)))
+    }
+
+    /// Get the number of orbitals that can still be sold under the sale and phase limits
+    pub fn remaining_supply(&self) -> u128 {
+        let remaining = self.limit().saturating_sub(self.sold());
+        match self.current_phase() {
+            Some((index, phase)) if phase.limit != 0 => {
+                remaining.min(phase.limit.saturating_sub(self.phase_sold(index)))
+            },
+            _ => remaining,
+        }
+    }
+
+    /// Get the current block height
+    pub fn current_height(&self) -> u128 {
+        self.height() as u128
+    }
+
+    /// Get the price of the next orbital at the current block height
+    pub fn current_price(&self) -> Result<u128> {
+        if let Some(auction) = self.dutch_auction() {
+            return Ok(auction.price_at(self.current_height()));
+        }
+        if let Some(curve) = self.bonding_curve() {
+            return curve.price_of(self.sold());
+        }
+
+        // An active phase may set its own fixed price
+        match self.current_phase() {
+            Some((_, phase)) if phase.price != 0 => Ok(phase.price),
+            _ => Ok(self.price()),
+        }
+    }
+
+    /// Get the pointer to the price paid for the most recent orbital
+    pub fn last_price_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/last-price")
+    }
+
+    /// Get the price paid for the most recent orbital
+    pub fn last_price(&self) -> u128 {
+        self.last_price_pointer().get_value::<u128>()
+    }
+
+    /// Set the price paid for the most recent orbital
+    pub fn set_last_price(&self, price: u128) {
+        self.last_price_pointer().set_value::<u128>(price);
+    }
+
+    /// Get the pointer to the rebate flag
+    pub fn rebate_enabled_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/rebate-enabled")
+    }
+
+    /// Check if early Dutch auction buyers are rebated down to the clearing price
+    pub fn rebate_enabled(&self) -> bool {
+        self.dutch_auction().is_some() && self.rebate_enabled_pointer().get_value::<u8>() == 1
+    }
+
+    /// Set the rebate flag
+    pub fn set_rebate_enabled(&self, enabled: bool) {
+        self.rebate_enabled_pointer().set_value::<u8>(if enabled { 1 } else { 0 });
+    }
+
+    /// Check if the Dutch auction has settled
+    ///
+    /// The auction settles when it sells out or its price reaches the floor,
+    /// since the clearing price can no longer fall after either
+    pub fn auction_settled(&self) -> bool {
+        match self.dutch_auction() {
+            Some(auction) => self.sold() >= self.limit() || auction.at_floor(self.current_height()),
+            None => false,
+        }
+    }
+
+    /// Get the Dutch auction clearing price
+    ///
+    /// This is the last price paid once the auction sells out, and the floor otherwise
+    pub fn clearing_price(&self) -> u128 {
+        match self.dutch_auction() {
+            Some(_) if self.sold() >= self.limit() => self.last_price(),
+            Some(auction) => auction.floor_price,
+            None => self.price(),
+        }
+    }
+
+    /// Get the pointer to the price paid for an orbital sold with a rebate
+    pub fn paid_pointer(&self, orbital_id: &AlkaneId) -> StoragePointer {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&orbital_id.block.to_le_bytes());
+        bytes.extend_from_slice(&orbital_id.tx.to_le_bytes());
+        StoragePointer::from_keyword("/paid/").select(&bytes)
+    }
+
+    /// Get the pointer to the rebate claimed flag for an orbital
+    pub fn rebated_pointer(&self, orbital_id: &AlkaneId) -> StoragePointer {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&orbital_id.block.to_le_bytes());
+        bytes.extend_from_slice(&orbital_id.tx.to_le_bytes());
+        StoragePointer::from_keyword("/rebated/").select(&bytes)
+    }
+
+    /// Get the pointer to the rebate reserve
+    pub fn rebate_reserve_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/rebate-reserve")
+    }
+
+    /// Get the amount paid above the floor by orbitals whose rebate is unclaimed
+    pub fn rebate_reserve(&self) -> u128 {
+        self.rebate_reserve_pointer().get_value::<u128>()
+    }
+
+    /// Set the rebate reserve
+    pub fn set_rebate_reserve(&self, reserve: u128) {
+        self.rebate_reserve_pointer().set_value::<u128>(reserve);
+    }
+
+    /// Get the pointer to the count of orbitals with an unclaimed rebate
+    pub fn rebate_unclaimed_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/rebate-unclaimed")
+    }
+
+    /// Get the count of orbitals with an unclaimed rebate
+    pub fn rebate_unclaimed(&self) -> u128 {
+        self.rebate_unclaimed_pointer().get_value::<u128>()
+    }
+
+    /// Set the count of orbitals with an unclaimed rebate
+    pub fn set_rebate_unclaimed(&self, count: u128) {
+        self.rebate_unclaimed_pointer().set_value::<u128>(count);
+    }
+
+    /// Record the price paid for an orbital so its rebate can be claimed later
+    pub fn record_rebate(&self, orbital_id: &AlkaneId, price: u128) -> Result<()> {
+        let floor_price = self.dutch_auction().map(|auction| auction.floor_price).unwrap_or(price);
+
+        self.paid_pointer(orbital_id).set_value::<u128>(price);
+        self.set_rebate_reserve(overflow_error(self.rebate_reserve().checked_add(price - floor_price))
+            .map_err(|_| anyhow!("rebate reserve overflow"))?);
+        self.set_rebate_unclaimed(self.rebate_unclaimed() + 1);
+        Ok(())
+    }
+
+    /// Get the proceeds that must stay in the sale to pay rebates
+    ///
+    /// Before the auction settles this is everything paid above the floor. Afterwards
+    /// only the part above the clearing price is owed.
+    pub fn reserved_rebates(&self) -> u128 {
+        let reserve = self.rebate_reserve();
+        match self.dutch_auction() {
+            Some(auction) if self.auction_settled() => {
+                let released = self.rebate_unclaimed()
+                    .saturating_mul(self.clearing_price().saturating_sub(auction.floor_price));
+                reserve.saturating_sub(released)
+            },
+            _ => reserve,
+        }
+    }
+
+    /// Get the pointer to the terms of service versions
+    pub fn terms_history_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/terms-history")
+    }
+
+    /// Get every published version of the terms of service, oldest first
+    pub fn terms_history(&self) -> Vec<TermsVersion> {
+        self.terms_history_pointer()
+            .get_list()
+            .into_iter()
+            .filter_map(|bytes| TermsVersion::from_bytes(&bytes).ok())
+            .collect()
+    }
+
+    /// Publish a version of the terms of service
+    ///
+    /// Returns the new version number (counting from 1)
+    pub fn publish_terms(&self, version: &TermsVersion) -> u128 {
+        self.terms_history_pointer().append(Arc::new(version.to_bytes()));
+        self.terms_history_pointer().length() as u128
+    }
+
+    /// Get the pointer to the flag requiring buyers to accept the terms of service
+    pub fn terms_required_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/terms-required")
+    }
+
+    /// Check if buyers must accept the terms of service with each purchase
+    pub fn terms_required(&self) -> bool {
+        self.terms_required_pointer().get_value::<u8>() == 1
+    }
+
+    /// Get the pointer to the terms version a buyer last accepted
+    pub fn terms_accepted_pointer(&self, key: &BuyerKey) -> StoragePointer {
+        StoragePointer::from_keyword("/terms-accepted/").select(&key.to_vec())
+    }
+
+    /// Check if the sale has opened
+    ///
+    /// A sale without a Dutch auction or phases takes purchases from initialization. A scheduled
+    /// sale opens when something has sold or the auction or a phase has started.
+    pub fn sale_opened(&self) -> bool {
+        let height = self.current_height();
+        let auction = self.dutch_auction();
+        let phases = self.phases();
+        if auction.is_none() && phases.is_empty() {
+            return true;
+        }
+
+        self.sold() > 0
+            || auction.is_some_and(|auction| height >= auction.start_height)
+            || phases.iter().any(|phase| height >= phase.start_height)
+    }
+
+    /// Check if the terms of service can change: while purchases are paused, or before a
+    /// scheduled sale opens, so buyers never see them change under a purchase
+    pub fn terms_changeable(&self) -> bool {
+        self.paused() || !self.sale_opened()
+    }
+
+    /// Get the current terms of service: the text, or the URI of hash-committed terms
+    pub fn terms_of_service(&self) -> String {
+        self.terms_history()
+            .pop()
+            .map(|version| version.body)
+            .unwrap_or_else(Self::default_terms_of_service)
+    }
+
+    /// Get the terms of service published when Initialize gives none
+    pub fn default_terms_of_service() -> String {
+        "TERMS OF SERVICE AND SALE\n\n\
+        By using this service and purchasing non-fungible units of this digital asset, you agree to the following terms:\n\n\
+        1. The digital assets provided are sold as-is without any warranty, express or implied.\n\
+        2. The seller is not liable for any damages arising from the use of these digital assets.\n\
+        3. You assume all risks associated with the use of these digital assets.\n\
+        4. These digital assets are released without warranty to be used at your own risk.\n\
+        5. The seller makes no guarantees regarding the value, utility, or functionality of these digital assets.\n\
+        6. By completing a purchase, you acknowledge that you have read and agree to these terms.\n\n\
+        All sales are final. No refunds will be provided under any circumstances."
+            .to_string()
+    }
+
+    /// Get the pointer to the paused flag
+    pub fn paused_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/paused")
+    }
+
+    /// Check if purchases are paused
+    pub fn paused(&self) -> bool {
+        self.paused_pointer().get_value::<u8>() == 1
+    }
+
+    /// Get the pointer to the configuration change log
+    pub fn config_log_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/config-log")
+    }
+
+    /// Get every configuration change made since initialization
+    pub fn config_log(&self) -> Vec<ConfigChange> {
+        self.config_log_pointer()
+            .get_list()
+            .into_iter()
+            .filter_map(|bytes| ConfigChange::from_bytes(&bytes).ok())
+            .collect()
+    }
+
+    /// Record a configuration change at the current block height
+    pub fn record_config_change(&self, field: u128, value: u128) {
+        self.record_config_detail(field, value, NO_DETAIL);
+    }
+
+    /// Record a configuration change with a detail at the current block height
+    pub fn record_config_detail(&self, field: u128, value: u128, detail: [u8; 32]) {
+        let change = ConfigChange { height: self.current_height(), field, value, detail };
+        self.config_log_pointer().append(Arc::new(change.to_bytes()));
+    }
+
+    /// Get the fuel amount for calls
+    pub fn fuel(&self) -> u64 {
+        // Default fuel value
+        1000000
+    }
+
+    /// Observe initialization to prevent multiple initializations
+    pub fn observe_initialization(&self) -> Result<()> {
+        let mut pointer = StoragePointer::from_keyword("/initialized");
+        if pointer.get().len() == 0 {
+            pointer.set_value::<u8>(0x01);
+            Ok(())
+        } else {
+            Err(anyhow!("already initialized"))
+        }
+    }
+
+    /// Calculate the number of orbitals, up to `max`, that can be purchased with the given
+    /// payment amount and the change left over
+    ///
+    /// On a bonding curve each orbital is priced by its position in the sale, so the
+    /// count is searched for over the curve's costs
+    pub fn calculate_purchase_count(&self, payment_amount: u128, max: u128) -> Result<(u128, u128)> {
+        if let Some(curve) = self.bonding_curve() {
+            let (count, cost) = curve.affordable(self.sold(), payment_amount, max)?;
+            return Ok((count, payment_amount - cost));
+        }
+
+        let price = self.current_price()?;
+        if price == 0 {
+            return Ok((0, payment_amount));
+        }
+        
+        let count = (payment_amount / price).min(max);
+        let change = payment_amount - count * price;
+        
+        Ok((count, change))
+    }
+
+    /// Get the cost of buying the next given number of orbitals
+    pub fn purchase_cost(&self, count: u128) -> Result<u128> {
+        if let Some(curve) = self.bonding_curve() {
+            return curve.cost(self.sold(), count);
+        }
+
+        overflow_error(self.current_price()?.checked_mul(count))
+            .map_err(|_| anyhow!("purchase cost overflow"))
+    }
+
+    /// Calculate how many orbitals a purchase fills and the change to return
+    ///
+    /// The count is the smallest of the requested quantity (0 for no preference),
+    /// the number the payment covers and the supply remaining under the sale and phase limits
+    pub fn calculate_fill(&self, payment_amount: u128, quantity: u128) -> Result<(u128, u128)> {
+        let mut max = self.remaining_supply();
+        if quantity != 0 {
+            max = max.min(quantity);
+        }
+
+        self.calculate_purchase_count(payment_amount, max)
+    }
+
+    /// Calculate how many orbitals a voucher purchase fills at the voucher's price
+    pub fn calculate_voucher_fill(&self, payment_amount: u128, quantity: u128, price: u128) -> Result<u128> {
+        let affordable = if price == 0 { u128::MAX } else { payment_amount / price };
+        let mut count = affordable.min(self.remaining_supply());
+        if quantity != 0 {
+            count = count.min(quantity);
+        }
+        Ok(count)
+    }
+
+    /// Initialize the sale
+    fn initialize(
+        &self,
+        collection_alkane_block: u128,
+        collection_alkane_tx: u128,
+        payment_alkane_block: u128,
+        payment_alkane_tx: u128,
+        price: u128,
+        limit: u128
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Prevent multiple initializations
+        self.observe_initialization()?;
+
+        // Create the collection alkane ID
+        let collection_id = AlkaneId {
+            block: collection_alkane_block,
+            tx: collection_alkane_tx,
+        };
+
+        // Create the payment alkane ID
+        let payment_id = AlkaneId {
+            block: payment_alkane_block,
+            tx: payment_alkane_tx,
+        };
+
+        // Set the collection alkane ID
+        self.set_collection_alkane_id(&collection_id);
+
+        // Set the payment alkane ID
+        self.set_payment_alkane_id(&payment_id);
+
+        // The base payment alkane starts the price table
+        self.set_payments(&[PaymentEntry { id: payment_id, price }]);
+
+        // Set the price
+        self.set_price(price);
+
+        // Set the limit
+        self.set_limit(limit);
+
+        // Initialize the sold counter
+        self.set_sold(0);
+
+        // Apply the optional configuration sections that follow the fixed arguments
+        let mut reader = InputReader::trailing(&context.inputs, 6);
+        while !reader.is_empty() {
+            match reader.next_u128()? {
+                init_tags::DUTCH_AUCTION => self.configure_dutch_auction(&mut reader)?,
+                init_tags::BONDING_CURVE => self.configure_bonding_curve(&mut reader)?,
+                init_tags::PHASES => self.configure_phases(&mut reader)?,
+                init_tags::CAPS => {
+                    self.set_max_per_purchase(reader.next_u128()?);
+                    self.set_max_per_buyer(reader.next_u128()?);
+                },
+                init_tags::VOUCHER_SIGNER => {
+                    let signer = merkle::hash_from_inputs(reader.next_u128()?, reader.next_u128()?);
+                    XOnlyPublicKey::from_slice(&signer)
+                        .map_err(|e| anyhow!("Invalid voucher signer: {}", e))?;
+                    self.voucher_signer_pointer().set(Arc::new(signer.to_vec()));
+                },
+                init_tags::BENEFICIARIES => self.configure_beneficiaries(&mut reader)?,
+                init_tags::REFERRALS => {
+                    let bps = reader.next_u128()?;
+                    if bps > BPS_DENOMINATOR {
+                        return Err(anyhow!("Referral cut of {} bps is more than the whole payment", bps));
+                    }
+                    self.referral_bps_pointer().set_value::<u128>(bps);
+                },
+                init_tags::CROWDFUND => {
+                    let crowdfund = Crowdfund { minimum: reader.next_u128()?, deadline: reader.next_u128()? };
+                    if crowdfund.minimum == 0 || crowdfund.minimum > self.limit() {
+                        return Err(anyhow!("Crowdfund minimum must be between 1 and the sale limit"));
+                    }
+                    if !crowdfund.accepts_purchases(self.current_height()) {
+                        return Err(anyhow!("Crowdfund deadline {} has already passed", crowdfund.deadline));
+                    }
+                    self.set_crowdfund(&crowdfund);
+                },
+                init_tags::TERMS => {
+                    if !self.terms_history().is_empty() {
+                        return Err(anyhow!("Terms of service given twice"));
+                    }
+                    let required = reader.next_u128()?;
+                    if required > 1 {
+                        return Err(anyhow!("Terms required flag must be 0 or 1"));
+                    }
+                    self.terms_required_pointer().set_value::<u8>(required as u8);
+                    self.publish_terms(&TermsVersion::read(&mut reader, self.current_height())?);
+                },
+                tag => return Err(anyhow!("Unknown configuration section {}", tag)),
+            }
+        }
+
+        // Without terms of their own the sale publishes the default ones as the first version
+        if self.terms_history().is_empty() {
+            self.publish_terms(&TermsVersion::from_text(&Self::default_terms_of_service(), self.current_height()));
+        }
+
+        // Phase prices replace the fixed price, so they cannot be combined with other pricing modes
+        if self.pricing_mode() != pricing_modes::FIXED && self.phases().iter().any(|phase| phase.price != 0) {
+            return Err(anyhow!("Phase prices can only be used with fixed pricing"));
+        }
+
+        // Refunds share the escrow between receipts, so every receipt must cost the same
+        if self.crowdfund().is_some()
+            && (self.pricing_mode() != pricing_modes::FIXED || self.phases().iter().any(|phase| phase.price != 0))
+        {
+            return Err(anyhow!("Crowdfunds can only be used with a single fixed price"));
+        }
+
+        // Mint the auth token to the deployer, who uses it to withdraw proceeds
+        response.alkanes.0.push(self.deploy_auth_token(1)?);
+
+        Ok(response)
+    }
+
+    /// Read the Dutch auction section of the Initialize inputs
+    fn configure_dutch_auction(&self, reader: &mut InputReader) -> Result<()> {
+        if self.pricing_mode() != pricing_modes::FIXED {
+            return Err(anyhow!("Pricing mode already configured"));
+        }
+
+        let auction = DutchAuction {
+            start_price: reader.next_u128()?,
+            floor_price: reader.next_u128()?,
+            start_height: reader.next_u128()?,
+            decay: reader.next_u128()?,
+            step_blocks: reader.next_u128()?.max(1),
+        };
+        let rebate = reader.next_u128()?;
+
+        if auction.floor_price > auction.start_price {
+            return Err(anyhow!("Auction floor price is above the start price"));
+        }
+        if auction.floor_price == 0 {
+            return Err(anyhow!("Auction floor price must be greater than zero"));
+        }
+
+        self.set_dutch_auction(&auction);
+        self.set_rebate_enabled(rebate == 1);
+        Ok(())
+    }
+
+    /// Read the beneficiaries section of the Initialize inputs
+    fn configure_beneficiaries(&self, reader: &mut InputReader) -> Result<()> {
+        let count = usize::try_from(reader.next_u128()?).map_err(|_| anyhow!("too many beneficiaries"))?;
+        if count == 0 {
+            self.set_beneficiaries(&[]);
+            return Ok(());
+        }
+        let dust = usize::try_from(reader.next_u128()?).map_err(|_| anyhow!("dust beneficiary out of range"))?;
+
+        if count > MAX_BENEFICIARIES {
+            return Err(anyhow!("At most {} beneficiaries are supported", MAX_BENEFICIARIES));
+        }
+
+        let mut beneficiaries = Vec::with_capacity(count);
+        for _ in 0..count {
+            beneficiaries.push(Beneficiary {
+                key: buyer_key_from_inputs(reader.next_u128()?, reader.next_u128()?),
+                bps: reader.next_u128()?,
+            });
+        }
+
+        let shares: Vec<u128> = beneficiaries.iter().map(|beneficiary| beneficiary.bps).collect();
+        validate_shares(&shares, dust)?;
+
+        self.set_beneficiaries(&beneficiaries);
+        self.dust_beneficiary_pointer().set_value::<u128>(dust as u128);
+        Ok(())
+    }
+
+    /// Read the bonding curve section of the Initialize inputs
+    fn configure_bonding_curve(&self, reader: &mut InputReader) -> Result<()> {
+        if self.pricing_mode() != pricing_modes::FIXED {
+            return Err(anyhow!("Pricing mode already configured"));
+        }
+
+        let (curve, consumed) = BondingCurve::from_inputs(reader.remaining())?;
+        reader.skip(consumed)?;
+
+        self.set_bonding_curve(&curve);
+        Ok(())
+    }
+
+    /// Read the phase schedule section of the Initialize inputs
+    fn configure_phases(&self, reader: &mut InputReader) -> Result<()> {
+        if !self.phases().is_empty() {
+            return Err(anyhow!("Phase schedule already configured"));
+        }
+
+        let count = reader.next_u128()?;
+        let mut phases = Vec::new();
+        for _ in 0..count {
+            phases.push(Phase::from_inputs(reader.remaining())?);
+            reader.skip(PHASE_INPUTS)?;
+        }
+        validate_schedule(&phases)?;
+
+        self.set_phases(&phases);
+        Ok(())
+    }
+
+    /// Set the allowlist entries for the buyer keys that follow the phase index
+    fn update_allowlist(&self, phase: u128, allowed: bool) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        let index = self.gated_phase(phase, gates::ALLOWLIST)?;
+
+        let mut reader = InputReader::trailing(&context.inputs, 1);
+        let mut keys = Vec::new();
+        while !reader.is_empty() {
+            let key = buyer_key_from_inputs(reader.next_u128()?, reader.next_u128()?);
+            self.allowlist_pointer(index, &key).set_value::<u8>(if allowed { 1 } else { 0 });
+            keys.extend_from_slice(&key);
+        }
+
+        let field = if allowed { config_fields::BUYERS_ALLOWED } else { config_fields::BUYERS_DISALLOWED };
+        self.record_config_detail(field, phase, digest_detail(&keys));
+
+        Ok(response)
+    }
+
+    /// Get the index of a phase in the schedule, checking it uses the given gate
+    fn gated_phase(&self, phase: u128, gate: u128) -> Result<usize> {
+        let phases = self.phases();
+        let index = usize::try_from(phase).ok()
+            .filter(|index| *index < phases.len())
+            .ok_or_else(|| anyhow!("No phase {}", phase))?;
+        if phases[index].gate != gate {
+            return Err(anyhow!("Phase {} does not use gate {}", phase, gate));
+        }
+        Ok(index)
+    }
+
+    /// Set the Merkle allowlist root for a phase
+    fn set_merkle_root(&self, phase: u128, root_low: u128, root_high: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        let index = self.gated_phase(phase, gates::MERKLE)?;
+        let root = merkle::hash_from_inputs(root_low, root_high);
+        self.merkle_root_pointer(index).set(Arc::new(root.to_vec()));
+        self.record_config_detail(config_fields::MERKLE_ROOT, phase, root);
+
+        Ok(response)
+    }
+
+    /// Add buyers to a phase allowlist
+    fn allow_buyers(&self, phase: u128) -> Result<CallResponse> {
+        self.update_allowlist(phase, true)
+    }
+
+    /// Remove buyers from a phase allowlist
+    fn disallow_buyers(&self, phase: u128) -> Result<CallResponse> {
+        self.update_allowlist(phase, false)
+    }
+
+    /// Find the phase a purchase falls in and check the buyer passes its gate
+    ///
+    /// Returns `None` when the sale has no schedule and is always open
+    fn admit_purchase(&self, options: &PurchaseOptions) -> Result<Option<Admission>> {
+        let context = self.context()?;
+        let phases = self.phases();
+        if phases.is_empty() {
+            return Ok(None);
+        }
+
+        let height = self.current_height();
+        let (index, phase) = active_phase(&phases, height)
+            .ok_or_else(|| anyhow!("No sale phase is active at height {}", height))?;
+
+        if phase.limit != 0 && self.phase_sold(index) >= phase.limit {
+            return Err(anyhow!("Phase {} limit reached", index));
+        }
+
+        let mut admission = Admission {
+            index,
+            phase,
+            merkle_buyer: None,
+            allowance: u128::MAX,
+        };
+
+        match phase.gate {
+            gates::ALLOWLIST => {
+                let key = caller_buyer_key(&self.transaction(), context.vout)?;
+                if !self.is_allowed(index, &key) {
+                    return Err(anyhow!("Buyer is not on the allowlist for phase {}", index));
+                }
+            },
+            gates::HOLDER => {
+                let holds = context.incoming_alkanes.0.iter()
+                    .any(|transfer| transfer.id == phase.gate_alkane && transfer.value > 0);
+                if !holds {
+                    return Err(anyhow!("Phase {} requires holding the gate alkane", index));
+                }
+            },
+            gates::VOUCHER => {
+                // The voucher itself is checked with the rest of the purchase
+                if options.voucher.is_none() {
+                    return Err(anyhow!("Phase {} requires a mint voucher", index));
+                }
+            },
+            gates::MERKLE => {
+                let root = self.merkle_root(index)
+                    .ok_or_else(|| anyhow!("Phase {} has no allowlist root", index))?;
+                let (max_quantity, proof) = options.merkle_proof.as_ref()
+                    .ok_or_else(|| anyhow!("Phase {} requires an allowlist proof", index))?;
+
+                let key = caller_buyer_key(&self.transaction(), context.vout)?;
+                if !merkle::verify(&root, &merkle::leaf_hash(&key, *max_quantity), proof) {
+                    return Err(anyhow!("Invalid allowlist proof for phase {}", index));
+                }
+
+                admission.allowance = max_quantity.saturating_sub(self.merkle_minted(index, &key));
+                if admission.allowance == 0 {
+                    return Err(anyhow!("Buyer has used their allowlist quantity for phase {}", index));
+                }
+                admission.merkle_buyer = Some(key);
+            },
+            _ => {},
+        }
+
+        Ok(Some(admission))
+    }
+
+    /// Add a payment alkane to the price table or change its price
+    fn add_payment(&self, block: u128, tx: u128, price: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        if price == 0 {
+            return Err(anyhow!("Payment price must be greater than zero"));
+        }
+
+        // The base price goes through UpdatePrice, which checks the pricing mode allows it
+        let id = AlkaneId { block, tx };
+        if id == self.payment_alkane_id() {
+            return Err(anyhow!("Change the base payment alkane's price with UpdatePrice"));
+        }
+
+        let mut payments = self.payments();
+        match payments.iter_mut().find(|entry| entry.id == id) {
+            Some(entry) => entry.price = price,
+            None => payments.push(PaymentEntry { id, price }),
+        }
+        self.set_payments(&payments);
+        self.record_config_detail(config_fields::PAYMENT_ADDED, price, alkane_detail(block, tx));
+
+        Ok(response)
+    }
+
+    /// Change the fixed price per orbital
+    fn update_price(&self, price: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        if self.pricing_mode() != pricing_modes::FIXED {
+            return Err(anyhow!("The price is set by the pricing mode"));
+        }
+        if self.crowdfund().is_some() {
+            return Err(anyhow!("Every crowdfund receipt must cost the same, so the price cannot change"));
+        }
+        if price == 0 {
+            return Err(anyhow!("Price must be greater than zero"));
+        }
+
+        // The base entry of the price table is the sale price
+        let base = self.payment_alkane_id();
+        let mut payments = self.payments();
+        if let Some(entry) = payments.iter_mut().find(|entry| entry.id == base) {
+            entry.price = price;
+        }
+        self.set_payments(&payments);
+        self.set_price(price);
+        self.record_config_change(config_fields::PRICE, price);
+
+        Ok(response)
+    }
+
+    /// Change the maximum number of orbitals that can be sold
+    fn update_limit(&self, limit: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        // The clearing price depends on whether the auction sold out, so moving the limit
+        // would change the rebates owed after the reserve was sized for them
+        if self.rebate_enabled() {
+            return Err(anyhow!("Limit cannot change while a Dutch auction offers rebates"));
+        }
+
+        let new_limit = if limit == 0 { u128::MAX } else { limit };
+        if new_limit < self.sold() {
+            return Err(anyhow!("Limit {} is below the {} orbitals already sold", limit, self.sold()));
+        }
+        if let Some(crowdfund) = self.crowdfund() {
+            if new_limit < crowdfund.minimum {
+                return Err(anyhow!("Limit {} is below the crowdfund minimum of {}", limit, crowdfund.minimum));
+            }
+        }
+
+        self.set_limit(limit);
+        self.record_config_change(config_fields::LIMIT, limit);
+
+        Ok(response)
+    }
+
+    /// Replace the beneficiaries
+    ///
+    /// Balances already credited stay claimable
+    fn update_beneficiaries(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        let mut reader = InputReader::trailing(&context.inputs, 0);
+        self.configure_beneficiaries(&mut reader)?;
+
+        // Log a hash of the whole set so buyers can check who is paid
+        let mut encoded = self.beneficiaries_pointer().get().as_ref().clone();
+        encoded.extend_from_slice(&(self.dust_beneficiary() as u128).to_le_bytes());
+        self.record_config_detail(
+            config_fields::BENEFICIARIES,
+            self.beneficiaries().len() as u128,
+            digest_detail(&encoded),
+        );
+
+        Ok(response)
+    }
+
+    /// Publish a new version of the terms of service
+    fn update_terms(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        if !self.terms_changeable() {
+            return Err(anyhow!("Terms of service can only change while the sale is paused or before it opens"));
+        }
+
+        let mut reader = InputReader::trailing(&context.inputs, 0);
+        let terms = TermsVersion::read(&mut reader, self.current_height())?;
+        let version = self.publish_terms(&terms);
+        self.record_config_change(config_fields::TERMS, version);
+
+        Ok(response)
+    }
+
+    /// Pause or resume purchases
+    fn set_paused(&self, paused: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        if paused > 1 {
+            return Err(anyhow!("Paused flag must be 0 or 1"));
+        }
+        self.paused_pointer().set_value::<u8>(paused as u8);
+        self.record_config_change(config_fields::PAUSED, paused);
+
+        Ok(response)
+    }
+
+    /// Remove a payment alkane from the price table
+    ///
+    /// Proceeds already paid in the alkane can still be withdrawn
+    fn remove_payment(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        // Every other price is converted through the base payment alkane
+        let id = AlkaneId { block, tx };
+        if id == self.payment_alkane_id() {
+            return Err(anyhow!("The base payment alkane cannot be removed"));
+        }
+
+        let mut payments = self.payments();
+        let count = payments.len();
+        payments.retain(|entry| entry.id != id);
+        if payments.len() == count {
+            return Err(anyhow!("Payment alkane {}:{} is not accepted", block, tx));
+        }
+        self.set_payments(&payments);
+        self.record_config_detail(config_fields::PAYMENT_REMOVED, 0, alkane_detail(block, tx));
+
+        Ok(response)
+    }
+
+    /// Withdraw base payment proceeds to the caller
+    fn withdraw(&self, amount: u128) -> Result<CallResponse> {
+        let payment_id = self.payment_alkane_id();
+        self.withdraw_payment(payment_id.block, payment_id.tx, amount)
+    }
+
+    /// Withdraw the proceeds of a payment alkane to the caller
+    fn withdraw_payment(&self, block: u128, tx: u128, amount: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.only_owner()?;
+
+        // Rebates owed to Dutch auction buyers are paid in the base payment alkane and cannot be withdrawn
+        let payment_id = AlkaneId { block, tx };
+        self.settle_crowdfund()?;
+        let proceeds = self.proceeds_of(&payment_id);
+        let reserved = if payment_id == self.payment_alkane_id() { self.reserved_rebates() } else { 0 };
+        let available = proceeds.saturating_sub(reserved);
+
+        // A zero amount withdraws the whole available balance
+        let amount = if amount == 0 { available } else { amount };
+        if amount == 0 {
+            return Err(anyhow!("No proceeds to withdraw"));
+        }
+        if amount > available {
+            return Err(anyhow!("Withdrawal of {} exceeds available proceeds of {}", amount, available));
+        }
+
+        self.set_proceeds(&payment_id, proceeds - amount);
+
+        // Beneficiaries claim their parts themselves
+        if !self.beneficiaries().is_empty() {
+            self.credit_beneficiaries(&payment_id, amount)?;
+            return Ok(response);
+        }
+
+        response.alkanes.0.push(AlkaneTransfer {
+            id: payment_id,
+            value: amount,
+        });
+
+        Ok(response)
+    }
+
+    /// Pay the caller's referral balance of a payment alkane
+    fn claim_referral(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let payment_id = AlkaneId { block, tx };
+        let key = caller_buyer_key(&self.transaction(), context.vout)?;
+        let balance = self.referral_balance(&payment_id, &key);
+        if balance == 0 {
+            return Err(anyhow!("No referral balance to claim"));
+        }
+        self.referral_balance_pointer(&payment_id, &key).set_value::<u128>(0);
+
+        response.alkanes.0.push(AlkaneTransfer {
+            id: payment_id,
+            value: balance,
+        });
+
+        Ok(response)
+    }
+
+    /// Pay the caller's beneficiary balance of a payment alkane
+    fn claim_share(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let payment_id = AlkaneId { block, tx };
+        let key = caller_buyer_key(&self.transaction(), context.vout)?;
+        let balance = self.share(&payment_id, &key);
+        if balance == 0 {
+            return Err(anyhow!("No share to claim"));
+        }
+        self.share_pointer(&payment_id, &key).set_value::<u128>(0);
+
+        response.alkanes.0.push(AlkaneTransfer {
+            id: payment_id,
+            value: balance,
+        });
+
+        Ok(response)
+    }
+
+    /// Mint an orbital through the collection
+    fn mint_orbital(&self) -> Result<AlkaneId> {
+        // Call the collection's CreateOrbital opcode
+        let cellpack = Cellpack {
+            target: self.collection_alkane_id(),
+            inputs: vec![77], // CreateOrbital opcode
+        };
+
+        let orbital_response = self.call(
+            &cellpack,
+            &AlkaneTransferParcel::default(),
+            self.fuel()
+        )?;
+
+        // Extract the orbital instance ID from the response
+        // The response data format is: [block(16 bytes)][tx(16 bytes)][index(16 bytes)]
+        if orbital_response.data.len() < 48 {
+            return Err(anyhow!("Invalid response from collection"));
+        }
+
+        Ok(AlkaneId {
+            block: u128::from_le_bytes(orbital_response.data[0..16].try_into().unwrap()),
+            tx: u128::from_le_bytes(orbital_response.data[16..32].try_into().unwrap()),
+        })
+    }
+
+    /// Count orbitals sold against the sale, the phase and the buyer
+    fn record_sale(&self, count: u128, admission: Option<&Admission>, buyer: Option<&BuyerKey>) -> Result<()> {
+        self.increment_sold(count)?;
+        if let Some(admission) = admission {
+            self.increment_phase_sold(admission.index, count)?;
+            if let Some(key) = &admission.merkle_buyer {
+                self.increment_merkle_minted(admission.index, key, count)?;
+            }
+        }
+        if let Some(key) = buyer {
+            self.increment_buyer_minted(key, count)?;
+        }
+        Ok(())
+    }
+
+    /// Hold a crowdfund purchase in escrow and issue a receipt for each orbital bought
+    ///
+    /// Receipts are units of the sale alkane itself
+    fn issue_receipts(
+        &self,
+        mut response: CallResponse,
+        payment_amount: u128,
+        count: u128,
+        admission: Option<&Admission>,
+        buyer: Option<&BuyerKey>,
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+
+        let cost = self.purchase_cost(count)?;
+        let change = payment_amount - cost;
+
+        self.record_sale(count, admission, buyer)?;
+        self.set_escrow(overflow_error(self.escrow().checked_add(cost))
+            .map_err(|_| anyhow!("escrow overflow"))?);
+        self.set_receipts(overflow_error(self.receipts().checked_add(count))
+            .map_err(|_| anyhow!("receipt counter overflow"))?);
+
+        // The purchase that reaches the minimum releases the escrow to the proceeds
+        self.settle_crowdfund()?;
+
+        response.alkanes.0.push(AlkaneTransfer {
+            id: context.myself,
+            value: count,
+        });
+        if change > 0 {
+            response.alkanes.0.push(AlkaneTransfer {
+                id: self.payment_alkane_id(),
+                value: change,
+            });
+        }
+
+        response.data = format!(
+            "Issued {} crowdfund receipts for {} of the payment alkane.",
+            count, cost
+        ).into_bytes();
+
+        Ok(response)
+    }
+
+    /// Redeem crowdfund receipts for orbitals or a refund
+    fn redeem_receipts(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::default();
+
+        let crowdfund = self.crowdfund().ok_or_else(|| anyhow!("Sale is not a crowdfund"))?;
+
+        // Receipts are units of the sale alkane; anything else sent is returned
+        let (receipts, refunds) = split_payment(&context.incoming_alkanes, &context.myself)?;
+        response.alkanes.0.extend(refunds);
+        if receipts == 0 {
+            return Err(anyhow!("No receipts sent"));
+        }
+
+        match self.crowdfund_state(&crowdfund) {
+            crowdfund_states::SUCCEEDED => {
+                self.settle_crowdfund()?;
+
+                // Mint an orbital per receipt, checking fuel before each mint
+                let mut minted_count = 0u128;
+                while minted_count < receipts {
+                    if <Self as AlkaneResponder>::fuel(&self) < 500000 {  // Minimum fuel needed for minting
+                        break;
+                    }
+                    response.alkanes.0.push(AlkaneTransfer {
+                        id: self.mint_orbital()?,
+                        value: 1u128,
+                    });
+                    minted_count += 1;
+                }
+
+                if minted_count == 0 {
+                    return Err(anyhow!("Not enough fuel to mint any orbitals"));
+                }
+                self.set_receipts(self.receipts().saturating_sub(minted_count));
+
+                // Return the receipts fuel did not cover
+                if minted_count < receipts {
+                    response.alkanes.0.push(AlkaneTransfer {
+                        id: context.myself,
+                        value: receipts - minted_count,
+                    });
+                }
+            },
+            crowdfund_states::FAILED => {
+                let outstanding = self.receipts();
+                let escrow = self.escrow();
+                let refund = refund_share(escrow, receipts, outstanding)?;
+                self.set_escrow(escrow - refund);
+                self.set_receipts(outstanding - receipts);
+
+                if refund > 0 {
+                    response.alkanes.0.push(AlkaneTransfer {
+                        id: self.payment_alkane_id(),
+                        value: refund,
+                    });
+                }
+            },
+            _ => {
+                return Err(anyhow!(
+                    "Crowdfund is open until {} orbitals are sold or height {}",
+                    crowdfund.minimum,
+                    crowdfund.deadline
+                ));
+            },
+        }
+
+        Ok(response)
+    }
+
+    /// Purchase an orbital
+    fn purchase(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::default();
+
+        // The admin can pause purchases
+        if self.paused() {
+            return Err(anyhow!("Sale is paused"));
+        }
+
+        // Check if the sale has reached its limit
+        if self.sold() >= self.limit() {
+            return Err(anyhow!("Sale limit reached"));
+        }
+
+        // A Dutch auction only accepts purchases from its start height
+        if let Some(auction) = self.dutch_auction() {
+            if self.current_height() < auction.start_height {
+                return Err(anyhow!("Auction starts at height {}", auction.start_height));
+            }
+        }
+
+        // Read the optional quantity that follows the opcode and the sections after it
+        let mut reader = InputReader::trailing(&context.inputs, 0);
+        let quantity = reader.next_u128_or(0);
+        let options = PurchaseOptions::read(&mut reader)?;
+
+        // Only sell during an open phase to buyers its gate admits
+        let admission = self.admit_purchase(&options)?;
+
+        // Work out which accepted payment alkane was sent
+        let payment = select_payment(&context.incoming_alkanes, &self.payments())
+            .ok_or_else(|| anyhow!("No payment provided"))?;
+        let payment_id = payment.id;
+
+        // A crowdfund only takes the base payment alkane at the sale price until its deadline
+        let crowdfund = self.crowdfund();
+        if let Some(crowdfund) = &crowdfund {
+            if !crowdfund.accepts_purchases(self.current_height()) {
+                return Err(anyhow!("Crowdfund closed at height {}", crowdfund.deadline));
+            }
+            if payment_id != self.payment_alkane_id() || options.voucher.is_some() || options.referrer.is_some() {
+                return Err(anyhow!("Crowdfund purchases take the base payment alkane without vouchers or referrers"));
+            }
+        }
+
+        // Total the payment across the incoming alkanes and return everything else
+        let (payment_amount, refunds) = split_payment(&context.incoming_alkanes, &payment_id)?;
+        response.alkanes.0.extend(refunds);
+
+        // Check if payment was provided
+        if payment_amount == 0 {
+            return Err(anyhow!("No payment provided"));
+        }
+
+        // Prices are kept in the base payment alkane, so work out the fill in its units
+        let base_payment = self.to_base_units(&payment, payment_amount)?;
+
+        // Check the buyer accepted the current terms of service when they passed a hash or must
+        let accepted = accepted_version(&self.terms_history(), options.terms.as_ref(), self.terms_required())?;
+
+        // Identify the buyer only when the sale keeps per-buyer counts, checks a voucher, pays a
+        // referrer or records terms acceptance
+        let referrer = options.referrer.filter(|_| self.referral_bps() != 0);
+        let buyer = if self.max_per_buyer() != 0 || options.voucher.is_some() || referrer.is_some() || accepted.is_some() {
+            Some(caller_buyer_key(&self.transaction(), context.vout)?)
+        } else {
+            None
+        };
+        if referrer.is_some() && referrer == buyer {
+            return Err(anyhow!("Buyers cannot refer themselves"));
+        }
+
+        // Record the terms version the buyer accepted
+        if let (Some(version), Some(key)) = (accepted, &buyer) {
+            self.terms_accepted_pointer(key).set_value::<u128>(version);
+        }
+
+        // A voucher is signed for this buyer and can only be used once
+        let voucher = options.voucher.as_ref();
+        if let (Some(voucher), Some(key)) = (voucher, &buyer) {
+            self.redeem_voucher(voucher, key)?;
+        }
+
+        // Hold the quantity to the mint caps, the phase gate's allowance and the voucher,
+        // naming the cap that leaves nothing to buy
+        let mut allowance = self.purchase_allowance(buyer.as_ref());
+        if allowance == 0 {
+            return Err(anyhow!("Buyer has reached the limit of {} orbitals", self.max_per_buyer()));
+        }
+        if let Some(admission) = &admission {
+            if admission.allowance == 0 {
+                return Err(anyhow!("Buyer has used their allowance for phase {}", admission.index));
+            }
+            allowance = allowance.min(admission.allowance);
+        }
+        if let Some(voucher) = voucher {
+            if voucher.quantity == 0 {
+                return Err(anyhow!("Voucher does not allow any orbitals"));
+            }
+            allowance = allowance.min(voucher.quantity);
+        }
+        let quantity = if quantity == 0 { allowance } else { quantity.min(allowance) };
+
+        // Fill as much of the order as the payment and remaining supply allow,
+        // at the voucher's price when one is used
+        let (purchase_count, price) = match voucher {
+            Some(voucher) => (self.calculate_voucher_fill(base_payment, quantity, voucher.price)?, voucher.price),
+            None => (self.calculate_fill(base_payment, quantity)?.0, self.current_price()?),
+        };
+        let rebate_enabled = self.rebate_enabled() && voucher.is_none() && payment_id == self.payment_alkane_id();
+        
+        // Check if at least one orbital can be purchased
+        if purchase_count == 0 {
+            return Err(anyhow!("Insufficient payment"));
+        }
+
+        // A crowdfund holds the payment in escrow and issues receipts instead of minting
+        if crowdfund.is_some() {
+            return self.issue_receipts(response, payment_amount, purchase_count, admission.as_ref(), buyer.as_ref());
+        }
+
+        // Create a vector to store the purchased orbitals
+        let mut purchased_orbitals = Vec::new();
+        let mut minted_count = 0u128;
+
+        // Purchase the orbitals, checking fuel before each mint
+        for _ in 0..purchase_count {
+            // Check if we have enough fuel for this mint
+            if <Self as AlkaneResponder>::fuel(&self) < 500000 {  // Minimum fuel needed for minting
+                break;
+            }
+
+            let orbital_id = self.mint_orbital()?;
+
+            // Remember what was paid so the buyer can claim a rebate once the auction settles
+            if rebate_enabled {
+                self.record_rebate(&orbital_id, price)?;
+            }
+
+            // Add the orbital to the purchased orbitals
+            purchased_orbitals.push(AlkaneTransfer {
+                id: orbital_id,
+                value: 1u128,
+            });
+
+            minted_count += 1;
+        }
+
+        if minted_count == 0 {
+            return Err(anyhow!("Not enough fuel to mint any orbitals"));
+        }
+
+        // Charge only for the orbitals actually minted and return the rest as change
+        let base_cost = match voucher {
+            Some(voucher) => overflow_error(voucher.price.checked_mul(minted_count))
+                .map_err(|_| anyhow!("purchase cost overflow"))?,
+            None => self.purchase_cost(minted_count)?,
+        };
+        let cost = self.from_base_units(&payment, base_cost)?;
+        let change = payment_amount - cost;
+
+        // Update the sold counters with the actual number minted
+        self.record_sale(minted_count, admission.as_ref(), buyer.as_ref())?;
+        if voucher.is_none() {
+            self.set_last_price(price);
+        }
+
+        // Credit the referrer's cut. While early buyers are owed rebates it is taken from the
+        // floor price, so the proceeds left always cover the rebates.
+        let referral_cut = match &referrer {
+            Some(referrer) => {
+                let referred = match self.dutch_auction() {
+                    Some(auction) if rebate_enabled => auction.floor_price.saturating_mul(minted_count),
+                    _ => cost,
+                };
+                let cut = share_of(referred, self.referral_bps());
+                self.credit_referral(&payment_id, referrer, minted_count, cut)?;
+                cut
+            },
+            None => 0,
+        };
+
+        // Keep the rest of the payment that was spent as proceeds for the seller
+        self.add_proceeds(&payment_id, cost - referral_cut)?;
+
+        // Add the purchased orbitals to the response
+        response.alkanes.0.extend(purchased_orbitals);
+
+        // Add change if any
+        if change > 0 {
+            response.alkanes.0.push(AlkaneTransfer {
+                id: payment_id,
+                value: change,
+            });
+        }
+
+        // Report the fill so callers can tell when fuel cut the purchase short
+        let mut info = format!(
+            "Successfully minted {} orbitals for {} of the payment alkane.",
+            minted_count, cost
+        ).into_bytes();
+
+        if minted_count < purchase_count {
+            info.extend(format!(
+                "\nCould only mint {} out of {} requested orbitals due to fuel limitations.",
+                minted_count, purchase_count
+            ).into_bytes());
+        }
+
+        response.data = info;
+
+        Ok(response)
+    }
+
+    /// Claim the Dutch auction rebate for the orbitals sent with the call
+    fn claim_rebate(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        // The orbitals are returned with the rebate
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let auction = self.dutch_auction()
+            .filter(|_| self.rebate_enabled())
+            .ok_or_else(|| anyhow!("Sale does not offer rebates"))?;
+        if !self.auction_settled() {
+            return Err(anyhow!("Auction has not settled"));
+        }
+
+        let clearing_price = self.clearing_price();
+        let mut rebate = 0u128;
+        let mut reserve = self.rebate_reserve();
+        let mut unclaimed = self.rebate_unclaimed();
+
+        for transfer in &context.incoming_alkanes.0 {
+            let paid = self.paid_pointer(&transfer.id).get_value::<u128>();
+            let mut rebated_pointer = self.rebated_pointer(&transfer.id);
+            if paid == 0 || rebated_pointer.get_value::<u8>() == 1 {
+                continue;
+            }
+
+            rebated_pointer.set_value::<u8>(1);
+            rebate += paid.saturating_sub(clearing_price);
+            reserve = reserve.saturating_sub(paid - auction.floor_price);
+            unclaimed -= 1;
+        }
+
+        if rebate == 0 {
+            return Err(anyhow!("No rebate to claim for the orbitals sent"));
+        }
+
+        self.set_rebate_reserve(reserve);
+        self.set_rebate_unclaimed(unclaimed);
+        let payment_id = self.payment_alkane_id();
+        let proceeds = self.proceeds()
+            .checked_sub(rebate)
+            .ok_or_else(|| anyhow!("Proceeds of {} cannot cover a rebate of {}", self.proceeds(), rebate))?;
+        self.set_proceeds(&payment_id, proceeds);
+
+        response.alkanes.0.push(AlkaneTransfer {
+            id: payment_id,
+            value: rebate,
+        });
+
+        Ok(response)
+    }
+
+    /// Get the collection alkane ID
+    fn get_collection_alkane_id(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Get the collection alkane ID
+        let collection_id = self.collection_alkane_id();
+        
+        // Serialize the AlkaneId to bytes
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&collection_id.block.to_le_bytes());
+        bytes.extend_from_slice(&collection_id.tx.to_le_bytes());
+        
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get the payment alkane ID
+    fn get_payment_alkane_id(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
         // Get the payment alkane ID
         let payment_id = self.payment_alkane_id();
@@ -410,12 +2498,12 @@ impl Sale {
         Ok(response)
     }
 
-    /// Get the price per orbital
+    /// Get the current price per orbital
     fn get_price(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        response.data = self.price().to_le_bytes().to_vec();
+        response.data = self.current_price()?.to_le_bytes().to_vec();
 
         Ok(response)
     }
@@ -440,7 +2528,7 @@ impl Sale {
         Ok(response)
     }
 
-    /// Get the terms of service
+    /// Get the current terms of service
     fn get_terms_of_service(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
@@ -449,8 +2537,274 @@ impl Sale {
 
         Ok(response)
     }
+
+    /// Get the payment proceeds held by the sale
+    fn get_proceeds(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.proceeds().to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get the Dutch auction state
+    fn get_auction(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let auction = self.dutch_auction().ok_or_else(|| anyhow!("Sale is not a Dutch auction"))?;
+
+        let mut bytes = Vec::with_capacity(128);
+        bytes.extend_from_slice(&auction.start_price.to_le_bytes());
+        bytes.extend_from_slice(&auction.floor_price.to_le_bytes());
+        bytes.extend_from_slice(&auction.start_height.to_le_bytes());
+        bytes.extend_from_slice(&auction.decay.to_le_bytes());
+        bytes.extend_from_slice(&auction.step_blocks.to_le_bytes());
+        bytes.extend_from_slice(&(self.rebate_enabled() as u128).to_le_bytes());
+        bytes.extend_from_slice(&self.clearing_price().to_le_bytes());
+        bytes.extend_from_slice(&(self.auction_settled() as u128).to_le_bytes());
+
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get the cost of buying the next `count` orbitals
+    fn quote_cost(&self, count: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let remaining = self.remaining_supply();
+        if count > remaining {
+            return Err(anyhow!("Only {} orbitals remain", remaining));
+        }
+
+        response.data = self.purchase_cost(count)?.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get how many orbitals a buyer can still purchase in one call
+    fn get_allowance(&self, key_low: u128, key_high: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let key = buyer_key_from_inputs(key_low, key_high);
+        let allowance = self.purchase_allowance(Some(&key)).min(self.remaining_supply());
+        response.data = allowance.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get the Merkle allowlist root for a phase
+    fn get_merkle_root(&self, phase: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let index = self.gated_phase(phase, gates::MERKLE)?;
+        response.data = self.merkle_root(index)
+            .ok_or_else(|| anyhow!("Phase {} has no allowlist root", phase))?
+            .to_vec();
+
+        Ok(response)
+    }
+
+    /// Get the x-only public key that signs mint vouchers
+    fn get_voucher_signer(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.voucher_signer()
+            .ok_or_else(|| anyhow!("Sale does not accept vouchers"))?
+            .to_vec();
+
+        Ok(response)
+    }
+
+    /// Check if a voucher nonce has been used
+    fn get_nonce_used(&self, nonce: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = (self.nonce_used(nonce) as u128).to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get the accepted payment alkanes
+    fn get_payments(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let payments = self.payments();
+        let mut bytes = (payments.len() as u128).to_le_bytes().to_vec();
+        for entry in &payments {
+            bytes.extend_from_slice(&entry.id.block.to_le_bytes());
+            bytes.extend_from_slice(&entry.id.tx.to_le_bytes());
+            bytes.extend_from_slice(&entry.price.to_le_bytes());
+            bytes.extend_from_slice(&self.proceeds_of(&entry.id).to_le_bytes());
+        }
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get every configuration change
+    fn get_config_log(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let log = self.config_log();
+        let mut bytes = (log.len() as u128).to_le_bytes().to_vec();
+        for change in &log {
+            bytes.extend(change.to_bytes());
+        }
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Check if purchases are paused
+    fn get_paused(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = (self.paused() as u128).to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get every version of the terms of service
+    fn get_terms_history(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = encode_history(&self.terms_history());
+
+        Ok(response)
+    }
+
+    /// Get the terms version a buyer last accepted
+    fn get_terms_accepted(&self, key_low: u128, key_high: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let key = buyer_key_from_inputs(key_low, key_high);
+        response.data = self.terms_accepted_pointer(&key).get_value::<u128>().to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get the crowdfund and how much it holds
+    fn get_crowdfund(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let crowdfund = self.crowdfund().ok_or_else(|| anyhow!("Sale is not a crowdfund"))?;
+        let mut bytes = Vec::with_capacity(80);
+        bytes.extend_from_slice(&crowdfund.minimum.to_le_bytes());
+        bytes.extend_from_slice(&crowdfund.deadline.to_le_bytes());
+        bytes.extend_from_slice(&self.crowdfund_state(&crowdfund).to_le_bytes());
+        bytes.extend_from_slice(&self.receipts().to_le_bytes());
+        bytes.extend_from_slice(&self.escrow().to_le_bytes());
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get a referrer's counts and unclaimed balances
+    fn get_referral_stats(&self, key_low: u128, key_high: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let key = buyer_key_from_inputs(key_low, key_high);
+        let payments = self.payments();
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.referral_bps().to_le_bytes());
+        bytes.extend_from_slice(&self.referral_purchases_pointer(&key).get_value::<u128>().to_le_bytes());
+        bytes.extend_from_slice(&self.referral_orbitals_pointer(&key).get_value::<u128>().to_le_bytes());
+        bytes.extend_from_slice(&(payments.len() as u128).to_le_bytes());
+        for entry in &payments {
+            bytes.extend_from_slice(&entry.id.block.to_le_bytes());
+            bytes.extend_from_slice(&entry.id.tx.to_le_bytes());
+            bytes.extend_from_slice(&self.referral_balance(&entry.id, &key).to_le_bytes());
+        }
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get the beneficiaries and their shares
+    fn get_beneficiaries(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let beneficiaries = self.beneficiaries();
+        let mut bytes = (beneficiaries.len() as u128).to_le_bytes().to_vec();
+        bytes.extend_from_slice(&(self.dust_beneficiary() as u128).to_le_bytes());
+        for beneficiary in &beneficiaries {
+            bytes.extend_from_slice(&beneficiary.key);
+            bytes.extend_from_slice(&beneficiary.bps.to_le_bytes());
+        }
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get a beneficiary's unclaimed balance of a payment alkane
+    fn get_share(&self, block: u128, tx: u128, key_low: u128, key_high: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let key = buyer_key_from_inputs(key_low, key_high);
+        response.data = self.share(&AlkaneId { block, tx }, &key).to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Encode a phase and the number sold in it for the phase views
+    fn phase_bytes(&self, index: usize, phase: &Phase) -> Vec<u8> {
+        phase.to_inputs()
+            .iter()
+            .chain(std::iter::once(&self.phase_sold(index)))
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    /// Get the active phase
+    fn get_phase(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let (index, phase) = self.current_phase()
+            .ok_or_else(|| anyhow!("No sale phase is active at height {}", self.current_height()))?;
+
+        let mut bytes = (index as u128).to_le_bytes().to_vec();
+        bytes.extend(self.phase_bytes(index, &phase));
+        response.data = bytes;
+
+        Ok(response)
+    }
+
+    /// Get the phase schedule
+    fn get_phases(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let phases = self.phases();
+        let mut bytes = (phases.len() as u128).to_le_bytes().to_vec();
+        for (index, phase) in phases.iter().enumerate() {
+            bytes.extend(self.phase_bytes(index, phase));
+        }
+        response.data = bytes;
+
+        Ok(response)
+    }
 }
 
+impl AuthenticatedResponder for Sale {}
+
 impl AlkaneResponder for Sale {
     fn execute(&self) -> Result<CallResponse> {
         // This method should not be called directly when using MessageDispatch
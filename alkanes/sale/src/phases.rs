@@ -0,0 +1,101 @@
+use alkanes_support::id::AlkaneId;
+use anyhow::{anyhow, Result};
+
+/// Gating rules that decide who may buy during a phase
+pub mod gates {
+    /// Anyone may buy
+    pub const PUBLIC: u128 = 0;
+    /// Only buyers on the phase allowlist may buy
+    pub const ALLOWLIST: u128 = 1;
+    /// Only buyers who send at least one unit of the gate alkane may buy (it is returned)
+    pub const HOLDER: u128 = 2;
+    /// Only buyers who prove membership in the phase's Merkle allowlist may buy,
+    /// up to the quantity in their leaf
+    pub const MERKLE: u128 = 3;
+    /// Only buyers with a mint voucher signed by the sale admin may buy
+    pub const VOUCHER: u128 = 4;
+}
+
+/// Number of u128 inputs that encode a phase
+pub const PHASE_INPUTS: usize = 7;
+
+/// A window of block heights during which the sale is open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Phase {
+    /// First block height of the phase
+    pub start_height: u128,
+    /// Block height the phase ends at (exclusive, 0 for no end)
+    pub end_height: u128,
+    /// Price per orbital during the phase (0 for the sale's own pricing)
+    pub price: u128,
+    /// Maximum number of orbitals sold during the phase (0 for no phase limit)
+    pub limit: u128,
+    /// Gating rule from `gates`
+    pub gate: u128,
+    /// Alkane buyers must hold for the holder gate
+    pub gate_alkane: AlkaneId,
+}
+
+impl Phase {
+    /// Check if the phase is open at the given block height
+    pub fn is_active(&self, height: u128) -> bool {
+        height >= self.start_height && (self.end_height == 0 || height < self.end_height)
+    }
+
+    /// Encode the phase as u128 inputs
+    pub fn to_inputs(&self) -> [u128; PHASE_INPUTS] {
+        [
+            self.start_height,
+            self.end_height,
+            self.price,
+            self.limit,
+            self.gate,
+            self.gate_alkane.block,
+            self.gate_alkane.tx,
+        ]
+    }
+
+    /// Decode a phase from u128 inputs
+    pub fn from_inputs(inputs: &[u128]) -> Result<Self> {
+        if inputs.len() < PHASE_INPUTS {
+            return Err(anyhow!("expected {} inputs for a phase but only {} remain", PHASE_INPUTS, inputs.len()));
+        }
+
+        let phase = Phase {
+            start_height: inputs[0],
+            end_height: inputs[1],
+            price: inputs[2],
+            limit: inputs[3],
+            gate: inputs[4],
+            gate_alkane: AlkaneId { block: inputs[5], tx: inputs[6] },
+        };
+
+        if phase.end_height != 0 && phase.end_height <= phase.start_height {
+            return Err(anyhow!("Phase ends at {} before it starts at {}", phase.end_height, phase.start_height));
+        }
+        if phase.gate > gates::VOUCHER {
+            return Err(anyhow!("Unknown phase gate {}", phase.gate));
+        }
+
+        Ok(phase)
+    }
+}
+
+/// Check a schedule's phases are in order and do not overlap
+pub fn validate_schedule(phases: &[Phase]) -> Result<()> {
+    for (i, pair) in phases.windows(2).enumerate() {
+        if pair[0].end_height == 0 || pair[0].end_height > pair[1].start_height {
+            return Err(anyhow!("Phase {} overlaps phase {}", i, i + 1));
+        }
+    }
+    Ok(())
+}
+
+/// Find the phase open at the given block height and its index in the schedule
+pub fn active_phase(phases: &[Phase], height: u128) -> Option<(usize, Phase)> {
+    phases
+        .iter()
+        .enumerate()
+        .find(|(_, phase)| phase.is_active(height))
+        .map(|(index, phase)| (index, *phase))
+}
@@ -0,0 +1,312 @@
+use anyhow::{anyhow, Result};
+
+/// Pricing modes selected at initialization
+pub mod pricing_modes {
+    pub const FIXED: u128 = 0;
+    pub const DUTCH_AUCTION: u128 = 1;
+    pub const BONDING_CURVE: u128 = 2;
+}
+
+/// Bonding curve kinds
+pub mod curve_kinds {
+    pub const LINEAR: u128 = 0;
+    pub const EXPONENTIAL: u128 = 1;
+    pub const TIERS: u128 = 2;
+}
+
+/// Fixed-point scale for exponential growth factors (1.0)
+pub const FIXED_POINT_ONE: u128 = 1_000_000_000;
+
+/// A Dutch auction where the price falls from a start price to a floor as blocks pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DutchAuction {
+    /// Price at the start height
+    pub start_price: u128,
+    /// Lowest price the auction falls to
+    pub floor_price: u128,
+    /// Block height the auction opens at
+    pub start_height: u128,
+    /// Amount the price falls by each step
+    pub decay: u128,
+    /// Number of blocks per step (1 for a linear fall every block)
+    pub step_blocks: u128,
+}
+
+impl DutchAuction {
+    /// Get the price at the given block height
+    ///
+    /// Before the start height the price is the start price
+    pub fn price_at(&self, height: u128) -> u128 {
+        let elapsed = height.saturating_sub(self.start_height);
+        let steps = elapsed / self.step_blocks.max(1);
+        let fall = steps.saturating_mul(self.decay);
+
+        self.start_price.saturating_sub(fall).max(self.floor_price)
+    }
+
+    /// Check if the price has reached the floor at the given block height
+    pub fn at_floor(&self, height: u128) -> bool {
+        self.price_at(height) == self.floor_price
+    }
+}
+
+/// A step in a tier table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tier {
+    /// Sold count the tier ends at (exclusive)
+    pub up_to: u128,
+    /// Price of each orbital in the tier
+    pub price: u128,
+}
+
+/// A bonding curve pricing the n-th orbital sold (counting from 0)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BondingCurve {
+    /// `base_price + slope * n`
+    Linear { base_price: u128, slope: u128 },
+    /// `base_price * (factor / FIXED_POINT_ONE) ^ n`
+    Exponential { base_price: u128, factor: u128 },
+    /// The price of the first tier whose `up_to` is above n, or the last tier past the table
+    Tiers(Vec<Tier>),
+}
+
+impl BondingCurve {
+    /// Check the curve can price orbitals
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            BondingCurve::Linear { .. } => Ok(()),
+            BondingCurve::Exponential { factor, .. } => {
+                if *factor < FIXED_POINT_ONE {
+                    return Err(anyhow!("Exponential factor must be at least {}", FIXED_POINT_ONE));
+                }
+                Ok(())
+            },
+            BondingCurve::Tiers(tiers) => {
+                if tiers.is_empty() {
+                    return Err(anyhow!("Tier table is empty"));
+                }
+                if tiers.windows(2).any(|pair| pair[0].up_to >= pair[1].up_to) {
+                    return Err(anyhow!("Tier thresholds must increase"));
+                }
+                Ok(())
+            },
+        }
+    }
+
+    /// Get the price of the n-th orbital sold
+    pub fn price_of(&self, n: u128) -> Result<u128> {
+        match self {
+            BondingCurve::Linear { base_price, slope } => slope
+                .checked_mul(n)
+                .and_then(|rise| rise.checked_add(*base_price))
+                .ok_or_else(|| anyhow!("price overflow")),
+            BondingCurve::Exponential { base_price, factor } => {
+                let growth = fixed_point_pow(*factor, n)?;
+                base_price
+                    .checked_mul(growth)
+                    .map(|price| price / FIXED_POINT_ONE)
+                    .ok_or_else(|| anyhow!("price overflow"))
+            },
+            BondingCurve::Tiers(tiers) => tiers
+                .iter()
+                .find(|tier| n < tier.up_to)
+                .or(tiers.last())
+                .map(|tier| tier.price)
+                .ok_or_else(|| anyhow!("Tier table is empty")),
+        }
+    }
+
+    /// Get the total cost of the next `count` orbitals when `sold` have already been sold
+    pub fn cost(&self, sold: u128, count: u128) -> Result<u128> {
+        match self {
+            BondingCurve::Linear { base_price, slope: 0 } => {
+                base_price.checked_mul(count).ok_or_else(|| anyhow!("purchase cost overflow"))
+            },
+            BondingCurve::Linear { base_price, slope } => {
+                // count * base + slope * (sold * count + count * (count - 1) / 2)
+                let steps = count
+                    .checked_mul(count.saturating_sub(1))
+                    .map(|pairs| pairs / 2)
+                    .and_then(|pairs| sold.checked_mul(count)?.checked_add(pairs));
+                steps
+                    .and_then(|steps| slope.checked_mul(steps))
+                    .and_then(|rise| base_price.checked_mul(count)?.checked_add(rise))
+                    .ok_or_else(|| anyhow!("purchase cost overflow"))
+            },
+            BondingCurve::Exponential { .. } => {
+                let mut total = 0u128;
+                for i in 0..count {
+                    let n = sold.checked_add(i).ok_or_else(|| anyhow!("sold count overflow"))?;
+                    total = total
+                        .checked_add(self.price_of(n)?)
+                        .ok_or_else(|| anyhow!("purchase cost overflow"))?;
+                }
+                Ok(total)
+            },
+            BondingCurve::Tiers(tiers) => {
+                // Charge each tier for the part of the range that falls inside it
+                let end = sold.checked_add(count).ok_or_else(|| anyhow!("sold count overflow"))?;
+                let mut total = 0u128;
+                let mut position = sold;
+                for (i, tier) in tiers.iter().enumerate() {
+                    if position >= end {
+                        break;
+                    }
+                    let tier_end = if i == tiers.len() - 1 { end } else { tier.up_to.min(end) };
+                    if position < tier_end {
+                        total = tier
+                            .price
+                            .checked_mul(tier_end - position)
+                            .and_then(|cost| total.checked_add(cost))
+                            .ok_or_else(|| anyhow!("purchase cost overflow"))?;
+                        position = tier_end;
+                    }
+                }
+                Ok(total)
+            },
+        }
+    }
+
+    /// Get how many of the next orbitals the payment covers, up to `max`, and their cost
+    ///
+    /// Costs only grow with the count, so this searches over `cost` instead of walking the
+    /// prices. The upper bound doubles from the bottom first, which keeps the search close to
+    /// the answer when `max` is far above it.
+    pub fn affordable(&self, sold: u128, payment: u128, max: u128) -> Result<(u128, u128)> {
+        let fits = |count: u128| self.cost(sold, count).ok().filter(|cost| *cost <= payment);
+
+        // The largest count known to fit and its cost
+        let mut best = (0u128, 0u128);
+
+        // The smallest count known not to fit, if any below `max` has been found
+        let mut step = 1u128;
+        let mut too_many = loop {
+            let count = best.0.saturating_add(step).min(max);
+            if count == best.0 {
+                break None;
+            }
+            match fits(count) {
+                Some(cost) => {
+                    best = (count, cost);
+                    step = step.saturating_mul(2);
+                },
+                None => break Some(count),
+            }
+        };
+
+        while let Some(upper) = too_many {
+            if upper - best.0 <= 1 {
+                break;
+            }
+            let count = best.0 + (upper - best.0) / 2;
+            match fits(count) {
+                Some(cost) => best = (count, cost),
+                None => too_many = Some(count),
+            }
+        }
+
+        Ok(best)
+    }
+
+    /// Encode the curve as its kind followed by its parameters
+    pub fn to_inputs(&self) -> Vec<u128> {
+        match self {
+            BondingCurve::Linear { base_price, slope } => vec![curve_kinds::LINEAR, *base_price, *slope],
+            BondingCurve::Exponential { base_price, factor } => {
+                vec![curve_kinds::EXPONENTIAL, *base_price, *factor]
+            },
+            BondingCurve::Tiers(tiers) => {
+                let mut inputs = vec![curve_kinds::TIERS, tiers.len() as u128];
+                for tier in tiers {
+                    inputs.push(tier.up_to);
+                    inputs.push(tier.price);
+                }
+                inputs
+            },
+        }
+    }
+
+    /// Decode a curve from its kind and parameters
+    ///
+    /// Returns the curve and the number of inputs consumed
+    pub fn from_inputs(inputs: &[u128]) -> Result<(Self, usize)> {
+        let value = |i: usize| inputs.get(i).copied().ok_or_else(|| anyhow!("missing curve parameter {}", i));
+
+        let (curve, consumed) = match value(0)? {
+            curve_kinds::LINEAR => (BondingCurve::Linear { base_price: value(1)?, slope: value(2)? }, 3),
+            curve_kinds::EXPONENTIAL => {
+                (BondingCurve::Exponential { base_price: value(1)?, factor: value(2)? }, 3)
+            },
+            curve_kinds::TIERS => {
+                let length = usize::try_from(value(1)?).map_err(|_| anyhow!("tier table is too large"))?;
+                if inputs.len() < 2 + length.saturating_mul(2) {
+                    return Err(anyhow!("expected {} tiers", length));
+                }
+                let tiers = (0..length)
+                    .map(|i| Tier { up_to: inputs[2 + i * 2], price: inputs[3 + i * 2] })
+                    .collect();
+                (BondingCurve::Tiers(tiers), 2 + length * 2)
+            },
+            kind => return Err(anyhow!("Unknown curve kind {}", kind)),
+        };
+
+        curve.validate()?;
+        Ok((curve, consumed))
+    }
+}
+
+/// Raise a fixed-point value to an integer power, rounding down after each multiplication
+fn fixed_point_pow(base: u128, exponent: u128) -> Result<u128> {
+    let multiply = |a: u128, b: u128| {
+        a.checked_mul(b)
+            .map(|product| product / FIXED_POINT_ONE)
+            .ok_or_else(|| anyhow!("price overflow"))
+    };
+
+    let mut result = FIXED_POINT_ONE;
+    let mut base = base;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(result, base)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = multiply(base, base)?;
+        }
+    }
+    Ok(result)
+}
+
+/// Convert an amount of a payment alkane priced at `payment_price` into units of the base
+/// payment alkane priced at `base_price`, rounding down
+pub fn to_base_units(amount: u128, payment_price: u128, base_price: u128) -> Result<u128> {
+    if payment_price == base_price {
+        return Ok(amount);
+    }
+    if payment_price == 0 {
+        return Err(anyhow!("payment alkane has no price"));
+    }
+
+    amount
+        .checked_mul(base_price)
+        .map(|scaled| scaled / payment_price)
+        .ok_or_else(|| anyhow!("payment conversion overflow"))
+}
+
+/// Convert an amount of the base payment alkane priced at `base_price` into units of a
+/// payment alkane priced at `payment_price`, rounding up so the sale is never underpaid
+pub fn from_base_units(amount: u128, payment_price: u128, base_price: u128) -> Result<u128> {
+    if payment_price == base_price {
+        return Ok(amount);
+    }
+    if base_price == 0 {
+        return Err(anyhow!("base payment alkane has no price"));
+    }
+
+    amount
+        .checked_mul(payment_price)
+        .and_then(|scaled| scaled.checked_add(base_price - 1))
+        .map(|scaled| scaled / base_price)
+        .ok_or_else(|| anyhow!("payment conversion overflow"))
+}
@@ -0,0 +1,55 @@
+use anyhow::Result;
+use orbitals_sale_contract::crowdfund::{crowdfund_states, refund_share, Crowdfund};
+
+const CROWDFUND: Crowdfund = Crowdfund { minimum: 100, deadline: 1000 };
+
+#[test]
+fn test_open_until_minimum_or_deadline() {
+    assert_eq!(CROWDFUND.state(0, 0), crowdfund_states::OPEN);
+    assert_eq!(CROWDFUND.state(99, 999), crowdfund_states::OPEN);
+    assert!(CROWDFUND.accepts_purchases(999));
+}
+
+#[test]
+fn test_succeeds_when_minimum_is_sold() {
+    // Reaching the minimum succeeds early and still counts after the deadline
+    assert_eq!(CROWDFUND.state(100, 500), crowdfund_states::SUCCEEDED);
+    assert_eq!(CROWDFUND.state(150, 5000), crowdfund_states::SUCCEEDED);
+}
+
+#[test]
+fn test_fails_at_deadline_short_of_minimum() {
+    assert_eq!(CROWDFUND.state(99, 1000), crowdfund_states::FAILED);
+    assert!(!CROWDFUND.accepts_purchases(1000));
+}
+
+#[test]
+fn test_refunds_return_the_whole_escrow() -> Result<()> {
+    // 7 receipts sharing 1000 units, redeemed in uneven batches
+    let mut escrow = 1000u128;
+    let mut outstanding = 7u128;
+    let mut refunded = 0u128;
+    for receipts in [1, 3, 2, 1] {
+        let refund = refund_share(escrow, receipts, outstanding)?;
+        assert!(refund >= 1000 / 7 * receipts);
+        escrow -= refund;
+        outstanding -= receipts;
+        refunded += refund;
+    }
+
+    assert_eq!(refunded, 1000);
+    assert_eq!(escrow, 0);
+    Ok(())
+}
+
+#[test]
+fn test_refund_of_large_escrow_does_not_overflow() -> Result<()> {
+    assert_eq!(refund_share(u128::MAX, 1, 2)?, u128::MAX / 2);
+    assert_eq!(refund_share(u128::MAX - 1, 3, 4)?, (u128::MAX - 1) / 4 * 3 + 1);
+    Ok(())
+}
+
+#[test]
+fn test_cannot_redeem_more_than_outstanding() {
+    assert!(refund_share(1000, 8, 7).is_err());
+}
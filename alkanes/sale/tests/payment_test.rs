@@ -0,0 +1,101 @@
+use alkanes_support::id::AlkaneId;
+use alkanes_support::parcel::{AlkaneTransfer, AlkaneTransferParcel};
+use anyhow::Result;
+use orbitals_sale_contract::{select_payment, split_payment, PaymentEntry};
+
+const PAYMENT: AlkaneId = AlkaneId { block: 2, tx: 0 };
+const OTHER: AlkaneId = AlkaneId { block: 2, tx: 7 };
+const ORBITAL: AlkaneId = AlkaneId { block: 2, tx: 42 };
+
+fn transfer(id: AlkaneId, value: u128) -> AlkaneTransfer {
+    AlkaneTransfer { id, value }
+}
+
+#[test]
+fn test_payment_only() -> Result<()> {
+    let incoming = AlkaneTransferParcel(vec![transfer(PAYMENT, 500)]);
+
+    let (payment, refunds) = split_payment(&incoming, &PAYMENT)?;
+
+    assert_eq!(payment, 500);
+    assert!(refunds.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_sums_every_payment_transfer() -> Result<()> {
+    let incoming = AlkaneTransferParcel(vec![
+        transfer(PAYMENT, 300),
+        transfer(PAYMENT, 200),
+        transfer(PAYMENT, 1),
+    ]);
+
+    let (payment, refunds) = split_payment(&incoming, &PAYMENT)?;
+
+    assert_eq!(payment, 501);
+    assert!(refunds.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_refunds_unrelated_alkanes_in_mixed_parcel() -> Result<()> {
+    let incoming = AlkaneTransferParcel(vec![
+        transfer(OTHER, 10),
+        transfer(PAYMENT, 300),
+        transfer(ORBITAL, 1),
+        transfer(PAYMENT, 200),
+    ]);
+
+    let (payment, refunds) = split_payment(&incoming, &PAYMENT)?;
+
+    assert_eq!(payment, 500);
+    assert_eq!(refunds, vec![transfer(OTHER, 10), transfer(ORBITAL, 1)]);
+    Ok(())
+}
+
+#[test]
+fn test_no_payment_refunds_everything() -> Result<()> {
+    let incoming = AlkaneTransferParcel(vec![transfer(OTHER, 10), transfer(ORBITAL, 1)]);
+
+    let (payment, refunds) = split_payment(&incoming, &PAYMENT)?;
+
+    assert_eq!(payment, 0);
+    assert_eq!(refunds, incoming.0);
+    Ok(())
+}
+
+#[test]
+fn test_empty_parcel() -> Result<()> {
+    let (payment, refunds) = split_payment(&AlkaneTransferParcel::default(), &PAYMENT)?;
+
+    assert_eq!(payment, 0);
+    assert!(refunds.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_payment_overflow_is_an_error() {
+    let incoming = AlkaneTransferParcel(vec![transfer(PAYMENT, u128::MAX), transfer(PAYMENT, 1)]);
+
+    assert!(split_payment(&incoming, &PAYMENT).is_err());
+}
+
+#[test]
+fn test_select_payment_takes_first_accepted_alkane() {
+    let payments = [PaymentEntry { id: PAYMENT, price: 100 }, PaymentEntry { id: OTHER, price: 300 }];
+    let incoming = AlkaneTransferParcel(vec![
+        transfer(ORBITAL, 1),
+        transfer(OTHER, 30),
+        transfer(PAYMENT, 10),
+    ]);
+
+    assert_eq!(select_payment(&incoming, &payments), Some(payments[1]));
+}
+
+#[test]
+fn test_select_payment_without_accepted_alkane() {
+    let payments = [PaymentEntry { id: PAYMENT, price: 100 }];
+    let incoming = AlkaneTransferParcel(vec![transfer(OTHER, 10), transfer(ORBITAL, 1)]);
+
+    assert_eq!(select_payment(&incoming, &payments), None);
+}
@@ -0,0 +1,75 @@
+use alkanes_support::id::AlkaneId;
+use anyhow::Result;
+use orbitals_sale_contract::phases::{active_phase, gates, validate_schedule, Phase};
+
+fn phase(start_height: u128, end_height: u128) -> Phase {
+    Phase {
+        start_height,
+        end_height,
+        price: 1000,
+        limit: 50,
+        gate: gates::PUBLIC,
+        gate_alkane: AlkaneId { block: 0, tx: 0 },
+    }
+}
+
+#[test]
+fn test_phase_window() {
+    let window = phase(100, 200);
+
+    assert!(!window.is_active(99));
+    assert!(window.is_active(100));
+    assert!(window.is_active(199));
+    assert!(!window.is_active(200));
+}
+
+#[test]
+fn test_open_ended_phase() {
+    let open = phase(100, 0);
+
+    assert!(!open.is_active(99));
+    assert!(open.is_active(u128::MAX));
+}
+
+#[test]
+fn test_active_phase_picks_by_height() {
+    let schedule = vec![phase(100, 200), phase(250, 300), phase(300, 0)];
+
+    assert_eq!(active_phase(&schedule, 50), None);
+    assert_eq!(active_phase(&schedule, 150), Some((0, schedule[0])));
+    assert_eq!(active_phase(&schedule, 220), None);
+    assert_eq!(active_phase(&schedule, 299), Some((1, schedule[1])));
+    assert_eq!(active_phase(&schedule, 1000), Some((2, schedule[2])));
+}
+
+#[test]
+fn test_phase_input_round_trip() -> Result<()> {
+    let gated = Phase {
+        gate: gates::HOLDER,
+        gate_alkane: AlkaneId { block: 2, tx: 9 },
+        ..phase(100, 200)
+    };
+
+    assert_eq!(Phase::from_inputs(&gated.to_inputs())?, gated);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_phases_are_rejected() {
+    // Ends before it starts
+    assert!(Phase::from_inputs(&phase(200, 100).to_inputs()).is_err());
+    // Unknown gate
+    assert!(Phase::from_inputs(&Phase { gate: 9, ..phase(100, 200) }.to_inputs()).is_err());
+    // Too few inputs
+    assert!(Phase::from_inputs(&[100, 200, 1000]).is_err());
+}
+
+#[test]
+fn test_overlapping_schedules_are_rejected() -> Result<()> {
+    validate_schedule(&[phase(100, 200), phase(200, 300)])?;
+
+    assert!(validate_schedule(&[phase(100, 201), phase(200, 300)]).is_err());
+    assert!(validate_schedule(&[phase(100, 0), phase(200, 300)]).is_err());
+    assert!(validate_schedule(&[phase(200, 300), phase(100, 150)]).is_err());
+    Ok(())
+}
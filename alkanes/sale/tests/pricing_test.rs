@@ -0,0 +1,227 @@
+use anyhow::Result;
+use orbitals_sale_contract::pricing::{
+    curve_kinds, from_base_units, to_base_units, BondingCurve, DutchAuction, Tier, FIXED_POINT_ONE,
+};
+
+const AUCTION: DutchAuction = DutchAuction {
+    start_price: 1000,
+    floor_price: 100,
+    start_height: 800_000,
+    decay: 50,
+    step_blocks: 1,
+};
+
+#[test]
+fn test_dutch_auction_start_price_before_start() {
+    assert_eq!(AUCTION.price_at(0), 1000);
+    assert_eq!(AUCTION.price_at(799_999), 1000);
+    assert_eq!(AUCTION.price_at(800_000), 1000);
+}
+
+#[test]
+fn test_dutch_auction_linear_decay() {
+    assert_eq!(AUCTION.price_at(800_001), 950);
+    assert_eq!(AUCTION.price_at(800_010), 500);
+}
+
+#[test]
+fn test_dutch_auction_stepwise_decay() {
+    let auction = DutchAuction { step_blocks: 6, ..AUCTION };
+
+    assert_eq!(auction.price_at(800_005), 1000);
+    assert_eq!(auction.price_at(800_006), 950);
+    assert_eq!(auction.price_at(800_017), 950);
+    assert_eq!(auction.price_at(800_018), 900);
+}
+
+#[test]
+fn test_dutch_auction_stops_at_floor() {
+    assert!(!AUCTION.at_floor(800_017));
+    assert!(AUCTION.at_floor(800_018));
+    assert_eq!(AUCTION.price_at(u128::MAX), 100);
+}
+
+#[test]
+fn test_dutch_auction_zero_step_is_per_block() {
+    let auction = DutchAuction { step_blocks: 0, ..AUCTION };
+
+    assert_eq!(auction.price_at(800_002), 900);
+}
+
+/// Sum the prices one at a time to check the cost calculations against
+fn summed_cost(curve: &BondingCurve, sold: u128, count: u128) -> Result<u128> {
+    (sold..sold + count).map(|n| curve.price_of(n)).sum()
+}
+
+fn tiers() -> BondingCurve {
+    BondingCurve::Tiers(vec![
+        Tier { up_to: 10, price: 100 },
+        Tier { up_to: 25, price: 150 },
+        Tier { up_to: 50, price: 300 },
+    ])
+}
+
+#[test]
+fn test_linear_curve_prices() -> Result<()> {
+    let curve = BondingCurve::Linear { base_price: 1000, slope: 10 };
+
+    assert_eq!(curve.price_of(0)?, 1000);
+    assert_eq!(curve.price_of(5)?, 1050);
+    Ok(())
+}
+
+#[test]
+fn test_linear_curve_sums_bulk_purchase() -> Result<()> {
+    let curve = BondingCurve::Linear { base_price: 1000, slope: 10 };
+
+    assert_eq!(curve.cost(0, 0)?, 0);
+    assert_eq!(curve.cost(0, 1)?, 1000);
+    assert_eq!(curve.cost(3, 4)?, 1030 + 1040 + 1050 + 1060);
+    assert_eq!(curve.cost(17, 33)?, summed_cost(&curve, 17, 33)?);
+    Ok(())
+}
+
+#[test]
+fn test_exponential_curve_prices() -> Result<()> {
+    // 10% growth per orbital
+    let curve = BondingCurve::Exponential { base_price: 1_000_000, factor: FIXED_POINT_ONE / 10 * 11 };
+
+    assert_eq!(curve.price_of(0)?, 1_000_000);
+    assert_eq!(curve.price_of(1)?, 1_100_000);
+    assert_eq!(curve.price_of(2)?, 1_210_000);
+    assert_eq!(curve.price_of(3)?, 1_331_000);
+    assert_eq!(curve.cost(1, 3)?, 1_100_000 + 1_210_000 + 1_331_000);
+    Ok(())
+}
+
+#[test]
+fn test_exponential_curve_overflow_is_an_error() {
+    let curve = BondingCurve::Exponential { base_price: 1_000_000, factor: 2 * FIXED_POINT_ONE };
+
+    assert!(curve.price_of(1000).is_err());
+}
+
+#[test]
+fn test_tier_prices() -> Result<()> {
+    let curve = tiers();
+
+    assert_eq!(curve.price_of(0)?, 100);
+    assert_eq!(curve.price_of(9)?, 100);
+    assert_eq!(curve.price_of(10)?, 150);
+    assert_eq!(curve.price_of(49)?, 300);
+    // Past the table the last tier continues
+    assert_eq!(curve.price_of(1000)?, 300);
+    Ok(())
+}
+
+#[test]
+fn test_tier_cost_spans_tiers() -> Result<()> {
+    let curve = tiers();
+
+    assert_eq!(curve.cost(8, 4)?, 2 * 100 + 2 * 150);
+    assert_eq!(curve.cost(0, 60)?, summed_cost(&curve, 0, 60)?);
+    assert_eq!(curve.cost(30, 5)?, 5 * 300);
+    Ok(())
+}
+
+#[test]
+fn test_affordable_sums_prices() -> Result<()> {
+    let curve = tiers();
+
+    // 2 at 100 and 2 at 150 cost 500, a fifth would cost 650
+    assert_eq!(curve.affordable(8, 600, 100)?, (4, 500));
+    // The count is capped by the maximum
+    assert_eq!(curve.affordable(8, 600, 3)?, (3, 350));
+    assert_eq!(curve.affordable(8, 99, 100)?, (0, 0));
+    Ok(())
+}
+
+#[test]
+fn test_affordable_matches_walking_the_prices() -> Result<()> {
+    let curves = [
+        BondingCurve::Linear { base_price: 100, slope: 7 },
+        BondingCurve::Exponential { base_price: 100, factor: FIXED_POINT_ONE * 11 / 10 },
+        tiers(),
+    ];
+
+    for curve in &curves {
+        for payment in [0, 99, 100, 1_000, 12_345, 100_000] {
+            let mut count = 0;
+            while summed_cost(curve, 3, count + 1)? <= payment {
+                count += 1;
+            }
+            assert_eq!(curve.affordable(3, payment, u128::MAX)?, (count, summed_cost(curve, 3, count)?));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_affordable_without_a_limit_stays_near_the_answer() -> Result<()> {
+    // A huge payment on a cheap curve is held to the maximum without walking each price
+    let curve = BondingCurve::Linear { base_price: 1, slope: 0 };
+    assert_eq!(curve.affordable(0, u128::MAX / 2, u128::MAX)?, (u128::MAX / 2, u128::MAX / 2));
+    assert_eq!(curve.affordable(0, u128::MAX / 2, 1)?, (1, 1));
+
+    // Free orbitals fill up to the maximum
+    let free = BondingCurve::Exponential { base_price: 0, factor: FIXED_POINT_ONE };
+    assert_eq!(free.affordable(0, 10, 40)?, (40, 0));
+    Ok(())
+}
+
+#[test]
+fn test_curve_input_round_trip() -> Result<()> {
+    for curve in [
+        BondingCurve::Linear { base_price: 1000, slope: 10 },
+        BondingCurve::Exponential { base_price: 1000, factor: FIXED_POINT_ONE * 2 },
+        tiers(),
+    ] {
+        let inputs = curve.to_inputs();
+        assert_eq!(BondingCurve::from_inputs(&inputs)?, (curve, inputs.len()));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_invalid_curves_are_rejected() {
+    // Thresholds out of order
+    assert!(BondingCurve::from_inputs(&[curve_kinds::TIERS, 2, 20, 100, 10, 200]).is_err());
+    // Empty tier table
+    assert!(BondingCurve::from_inputs(&[curve_kinds::TIERS, 0]).is_err());
+    // Missing tiers
+    assert!(BondingCurve::from_inputs(&[curve_kinds::TIERS, 2, 10, 100]).is_err());
+    // Shrinking exponential
+    assert!(BondingCurve::from_inputs(&[curve_kinds::EXPONENTIAL, 1000, FIXED_POINT_ONE - 1]).is_err());
+    // Unknown kind
+    assert!(BondingCurve::from_inputs(&[9, 1, 2]).is_err());
+}
+
+#[test]
+fn test_base_unit_conversion_is_identity_at_equal_prices() -> Result<()> {
+    assert_eq!(to_base_units(12345, 100, 100)?, 12345);
+    assert_eq!(from_base_units(12345, 100, 100)?, 12345);
+    Ok(())
+}
+
+#[test]
+fn test_base_unit_conversion_rounds_in_the_sales_favour() -> Result<()> {
+    // 3 of the other alkane buy what 1 base unit does
+    assert_eq!(to_base_units(10, 300, 100)?, 3);
+    assert_eq!(from_base_units(3, 300, 100)?, 9);
+    assert_eq!(from_base_units(1, 100, 300)?, 1);
+
+    // Charging the converted cost never takes more than was paid
+    for payment in 0..50u128 {
+        let base = to_base_units(payment, 7, 3)?;
+        assert!(from_base_units(base, 7, 3)? <= payment);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_base_unit_conversion_errors() {
+    assert!(to_base_units(10, 0, 100).is_err());
+    assert!(from_base_units(10, 100, 0).is_err());
+    assert!(to_base_units(u128::MAX, 1, 2).is_err());
+    assert!(from_base_units(u128::MAX, 2, 1).is_err());
+}
@@ -3,7 +3,7 @@
 import { Command } from 'commander';
 import * as fs from 'fs';
 import * as path from 'path';
-import { generateContainerFromFilePath, Wat2Wasm, dirPath } from './index';
+import { generateContainerFromFilePath, Wat2Wasm, dirPath, DEFAULT_CONTENT_TYPE } from './index';
 
 // Define the program
 const program = new Command();
@@ -19,7 +19,8 @@ program
   .argument('<input>', 'Input file path')
   .option('-o, --output <output>', 'Output file path', 'container.wasm')
   .option('-t, --template <template>', 'Template WAT file path')
-  .action(async (input: string, options: { output: string; template?: string }) => {
+  .option('-c, --content-type <type>', 'MIME type of the data (inferred from the file extension by default)')
+  .action(async (input: string, options: { output: string; template?: string; contentType?: string }) => {
     try {
       // Resolve the input path (handles both relative and absolute paths)
       const resolvedInputPath = path.resolve(input);
@@ -45,8 +46,11 @@ program
       // Resolve the template path if provided
       const templatePath = options.template ? path.resolve(options.template) : undefined;
       
+      // Use the given content type or infer it from the file extension
+      const contentType = options.contentType ?? inferContentType(resolvedInputPath);
+      
       // Generate the container
-      const containerOptions = templatePath ? { template: templatePath } : {};
+      const containerOptions = templatePath ? { template: templatePath, contentType } : { contentType };
       const wasm = await generateContainerFromFilePath(resolvedInputPath, wat2wasm, containerOptions);
 
       // Resolve the output path (handles both relative and absolute paths)
@@ -68,5 +72,33 @@ program
     }
   });
 
+/**
+ * Infer the MIME type of a file from its extension
+ * 
+ * @param filePath Path to the file
+ * @returns The MIME type, or application/octet-stream if the extension is unknown
+ */
+function inferContentType(filePath: string): string {
+  const contentTypes: Record<string, string> = {
+    '.png': 'image/png',
+    '.jpg': 'image/jpeg',
+    '.jpeg': 'image/jpeg',
+    '.gif': 'image/gif',
+    '.webp': 'image/webp',
+    '.avif': 'image/avif',
+    '.svg': 'image/svg+xml',
+    '.html': 'text/html',
+    '.htm': 'text/html',
+    '.txt': 'text/plain',
+    '.json': 'application/json',
+    '.js': 'text/javascript',
+    '.mp3': 'audio/mpeg',
+    '.mp4': 'video/mp4',
+    '.glb': 'model/gltf-binary',
+  };
+  
+  return contentTypes[path.extname(filePath).toLowerCase()] ?? DEFAULT_CONTENT_TYPE;
+}
+
 // Parse the command line arguments
 program.parse(process.argv);
\ No newline at end of file
@@ -4,208 +4,251 @@
   (import "env" "__request_context" (func $__request_context (result i32)))
   (import "env" "__load_context" (func $__load_context (param i32)))
 
-  ;; Memory declaration
-  (memory (export "memory") 1)
+  ;; Memory declaration - the page count is sized by our script to hold the data and scratch space
+  (memory (export "memory") MEMORY_PAGES)
 
   ;; Define our data section - this will be replaced by our script
   (data (i32.const 0) "DATA_PLACEHOLDER")
 
+  ;; Define the SHA-256 hash of the data and its MIME type - these will be replaced by our script
+  (data (i32.const CONTENT_HASH_OFFSET) "CONTENT_HASH_PLACEHOLDER")
+  (data (i32.const CONTENT_TYPE_OFFSET) "CONTENT_TYPE_PLACEHOLDER")
+
+  ;; Write a CallResponse with empty alkanes and the given payload
+  ;; Layout: [size(4 bytes)][alkanes_count(16 bytes)][payload]
+  ;; Returns the pointer the runtime expects (after the size)
+  (func $respond (param $buffer_ptr i32) (param $src i32) (param $size i32) (result i32)
+    ;; Store the size of our CallResponse as a 4-byte little-endian u32
+    (local.get $buffer_ptr)
+    (i32.const 16)                    ;; 16 bytes for alkanes count
+    (local.get $size)                 ;; Size of the payload
+    (i32.add)                         ;; Total size of CallResponse
+    (i32.store)
+
+    ;; Store alkanes count (0) as the next 16 bytes
+    (local.get $buffer_ptr)
+    (i32.const 4)
+    (i32.add)
+    (i64.const 0)                     ;; No alkanes (first 8 bytes)
+    (i64.store)
+
+    (local.get $buffer_ptr)
+    (i32.const 12)
+    (i32.add)
+    (i64.const 0)                     ;; No alkanes (second 8 bytes)
+    (i64.store)
+
+    ;; Copy the payload after the alkanes count
+    (local.get $buffer_ptr)
+    (i32.const 20)
+    (i32.add)                         ;; Destination address
+    (local.get $src)                  ;; Source address
+    (local.get $size)                 ;; Size to copy
+    (memory.copy)
+
+    ;; Return the pointer to the arraybuffer layout + 4
+    (local.get $buffer_ptr)
+    (i32.const 4)
+    (i32.add)
+  )
+
+  ;; Read a u128 input as an i32, saturating at the data size
+  ;; Values that do not fit in the low 8 bytes are treated as out of range
+  (func $load_clamped (param $ptr i32) (result i32)
+    ;; Check the high 8 bytes are zero and the low 8 bytes are within the data
+    (local.get $ptr)
+    (i32.const 8)
+    (i32.add)
+    (i64.load)
+    (i64.eqz)
+    (local.get $ptr)
+    (i64.load)
+    (i64.const DATA_SIZE)
+    (i64.le_u)
+    (i32.and)
+    (if (result i32)
+      (then
+        (local.get $ptr)
+        (i32.load)                    ;; Value fits, use the low 4 bytes
+      )
+      (else
+        (i32.const DATA_SIZE)         ;; Saturate at the data size
+      )
+    )
+  )
+
   ;; Export the __execute function
   (func (export "__execute") (result i32)
     ;; Define locals
     (local $context_size i32)
     (local $context_ptr i32)
+    (local $inputs_ptr i32)
     (local $buffer_ptr i32)
     (local $opcode i32)
-    (local $i i32)
-    (local $result i32)
-    (local $dest i32)
-    (local $src i32)
-    (local $size i32)
-    
+    (local $offset i32)
+    (local $length i32)
+
     ;; Request context size
     (call $__request_context)
     (local.set $context_size)
-    
-    ;; Allocate memory for context
-    (i32.const 512)                   ;; Context buffer at position 512
+
+    ;; Allocate memory for context after our data so loading it cannot overwrite the data
+    (i32.const SCRATCH_BASE)          ;; Context buffer after the data - will be replaced by script
     (local.set $context_ptr)
-    
+
     ;; Create arraybuffer layout for context
     (local.get $context_ptr)
     (local.get $context_size)
     (i32.store)                       ;; Store size at context_ptr
-    
+
     ;; Load context into memory
     (local.get $context_ptr)
     (i32.const 4)
     (i32.add)                         ;; context_ptr + 4 (after size)
     (call $__load_context)
-    
+
+    ;; The response buffer follows the context
+    (local.get $context_ptr)
+    (i32.const 4)
+    (i32.add)
+    (local.get $context_size)
+    (i32.add)
+    (local.set $buffer_ptr)
+
     ;; Parse context to get opcode (first input)
     ;; In a real implementation, we would parse the entire context structure
     ;; For simplicity, we'll assume the opcode is at a specific offset
     ;; Based on the Context struct, inputs are after myself, caller, vout, and incoming_alkanes
     ;; We'll use offset 100 as an approximation
+    ;; Each following input is a 16 byte u128
     (local.get $context_ptr)
     (i32.const 4)                     ;; Skip size
     (i32.add)
     (i32.const 100)                   ;; Approximate offset to first input
     (i32.add)
+    (local.tee $inputs_ptr)
     (i32.load)                        ;; Load the opcode
     (local.set $opcode)
-    
-    ;; Check if opcode is 1000 (GetData)
+
+    ;; Opcode 1000 (GetData) returns all of the data
     (local.get $opcode)
     (i32.const 1000)
     (i32.eq)
-    (if (result i32)                  ;; Explicitly declare that if returns i32
+    (if
       (then
-        ;; Opcode is 1000 (GetData), return the data
-        ;; Create a CallResponse with empty alkanes and our data
-        ;; Format of CallResponse: [alkanes_count(16 bytes)][data]
-        
-        ;; First, we need to create the CallResponse
-        ;; We'll allocate memory at position 1024 for our CallResponse
-        (i32.const 1024)              ;; Destination address for CallResponse
-        
-        ;; Store alkanes count (0) as first 16 bytes
-        (i64.const 0)                 ;; No alkanes (first 8 bytes)
-        (i64.store)                   ;; Store at position 1024
-        
-        (i32.const 1024)
-        (i32.const 8)
-        (i32.add)                     ;; Position 1024 + 8
-        (i64.const 0)                 ;; No alkanes (second 8 bytes)
-        (i64.store)                   ;; Store at position 1024 + 8
-        
-        ;; Now copy our data after the alkanes count
-        ;; Calculate destination address
-        (i32.const 1024)              ;; Destination base
-        (i32.const 16)                ;; Destination offset (after alkanes count)
-        (i32.add)                     ;; Destination address
-        (local.set $dest)             ;; Store in local
-        
-        ;; Set source address
+        (local.get $buffer_ptr)
         (i32.const 0)                 ;; Source address (our data)
-        (local.set $src)              ;; Store in local
-        
-        ;; Set size to copy
         (i32.const DATA_SIZE)         ;; Size to copy - will be replaced by script
-        (local.set $size)             ;; Store in local
-        
-        ;; Perform the memory copy
-        (local.get $dest)
-        (local.get $src)
-        (local.get $size)
-        (memory.copy)                 ;; Copy the data
-        
-        ;; Now we need to create the arraybuffer layout
-        ;; Format: [size(4 bytes)][data]
-        ;; Where data is our CallResponse: [alkanes_count(16 bytes)][data]
-        
-        ;; Calculate the total size of our CallResponse
-        (i32.const 16)                ;; 16 bytes for alkanes count
-        (i32.const DATA_SIZE)         ;; Size of our data - will be replaced by script
-        (i32.add)                     ;; Total size of CallResponse
-        
-        ;; Allocate memory at position 2048 for our arraybuffer layout
-        (i32.const 2048)              ;; Destination address for arraybuffer layout
-        (local.tee $buffer_ptr)       ;; Save the address in local
-        
-        ;; Store the size of our CallResponse as a 4-byte little-endian u32
-        (i32.const 16)                ;; 16 bytes for alkanes count
-        (i32.const DATA_SIZE)         ;; Size of our data - will be replaced by script
-        (i32.add)                     ;; Total size of CallResponse
-        (i32.store)                   ;; Store at position 2048
-        
-        ;; Now copy our CallResponse after the size
-        ;; Calculate destination address
-        (i32.const 2048)              ;; Destination base
-        (i32.const 4)                 ;; Destination offset (after size)
-        (i32.add)                     ;; Destination address
-        (local.set $dest)             ;; Store in local
-        
-        ;; Set source address
-        (i32.const 1024)              ;; Source address (our CallResponse)
-        (local.set $src)              ;; Store in local
-        
-        ;; Calculate size to copy
-        (i32.const 16)                ;; 16 bytes for alkanes count
-        (i32.const DATA_SIZE)         ;; Size of our data - will be replaced by script
-        (i32.add)                     ;; Total size to copy
-        (local.set $size)             ;; Store in local
-        
-        ;; Perform the memory copy
-        (local.get $dest)
-        (local.get $src)
-        (local.get $size)
-        (memory.copy)                 ;; Copy the data
-        
-        ;; Return the pointer to the arraybuffer layout + 4
-        (local.get $buffer_ptr)       ;; Get the base address
-        (i32.const 4)                 ;; Offset
-        (i32.add)                     ;; Add the offset - this value is returned
+        (call $respond)
+        (return)
       )
-      (else
-        ;; Opcode is not 1000, return an empty response
-        ;; Create an empty CallResponse with no alkanes and no data
-        
-        ;; First, we need to create the CallResponse
-        ;; We'll allocate memory at position 1024 for our CallResponse
-        (i32.const 1024)              ;; Destination address for CallResponse
-        
-        ;; Store alkanes count (0) as first 16 bytes
-        (i64.const 0)                 ;; No alkanes (first 8 bytes)
-        (i64.store)                   ;; Store at position 1024
-        
-        (i32.const 1024)
-        (i32.const 8)
-        (i32.add)                     ;; Position 1024 + 8
-        (i64.const 0)                 ;; No alkanes (second 8 bytes)
-        (i64.store)                   ;; Store at position 1024 + 8
-        
-        ;; Now we need to create the arraybuffer layout
-        ;; Format: [size(4 bytes)][data]
-        ;; Where data is our CallResponse: [alkanes_count(16 bytes)][data]
-        
-        ;; Calculate the total size of our CallResponse (just the alkanes count)
-        (i32.const 16)                ;; 16 bytes for alkanes count
-        
-        ;; Allocate memory at position 2048 for our arraybuffer layout
-        (i32.const 2048)              ;; Destination address for arraybuffer layout
-        (local.tee $buffer_ptr)       ;; Save the address in local
-        
-        ;; Store the size of our CallResponse as a 4-byte little-endian u32
-        (i32.const 16)                ;; 16 bytes for alkanes count (no data)
-        (i32.store)                   ;; Store at position 2048
-        
-        ;; Now copy our CallResponse after the size
-        ;; Calculate destination address
-        (i32.const 2048)              ;; Destination base
-        (i32.const 4)                 ;; Destination offset (after size)
-        (i32.add)                     ;; Destination address
-        (local.set $dest)             ;; Store in local
-        
-        ;; Set source address
-        (i32.const 1024)              ;; Source address (our CallResponse)
-        (local.set $src)              ;; Store in local
-        
-        ;; Set size to copy
-        (i32.const 16)                ;; 16 bytes for alkanes count (no data)
-        (local.set $size)             ;; Store in local
-        
-        ;; Perform the memory copy
-        (local.get $dest)
-        (local.get $src)
-        (local.get $size)
-        (memory.copy)                 ;; Copy the data
-        
-        ;; Return the pointer to the arraybuffer layout + 4
-        (local.get $buffer_ptr)       ;; Get the base address
-        (i32.const 4)                 ;; Offset
-        (i32.add)                     ;; Add the offset - this value is returned
+    )
+
+    ;; Opcode 1001 (GetDataSize) returns the data size as a u128
+    (local.get $opcode)
+    (i32.const 1001)
+    (i32.eq)
+    (if
+      (then
+        ;; Write the size where the payload goes, then respond with it in place
+        (local.get $buffer_ptr)
+        (i32.const 20)
+        (i32.add)
+        (i64.const DATA_SIZE)         ;; Low 8 bytes of the size
+        (i64.store)
+
+        (local.get $buffer_ptr)
+        (i32.const 28)
+        (i32.add)
+        (i64.const 0)                 ;; High 8 bytes of the size
+        (i64.store)
+
+        (local.get $buffer_ptr)
+        (local.get $buffer_ptr)
+        (i32.const 20)
+        (i32.add)                     ;; Source address (the size we just wrote)
+        (i32.const 16)                ;; A u128 is 16 bytes
+        (call $respond)
+        (return)
       )
     )
+
+    ;; Opcode 1002 (GetDataRange) returns the bytes in [offset, offset + length)
+    ;; The range is clamped to the end of the data
+    (local.get $opcode)
+    (i32.const 1002)
+    (i32.eq)
+    (if
+      (then
+        ;; Offset is the first input after the opcode
+        (local.get $inputs_ptr)
+        (i32.const 16)
+        (i32.add)
+        (call $load_clamped)
+        (local.set $offset)
+
+        ;; Length is the second input after the opcode
+        (local.get $inputs_ptr)
+        (i32.const 32)
+        (i32.add)
+        (call $load_clamped)
+        (local.set $length)
+
+        ;; Clamp the length to the bytes remaining after the offset
+        (local.get $length)
+        (i32.const DATA_SIZE)
+        (local.get $offset)
+        (i32.sub)                     ;; Bytes remaining
+        (i32.gt_u)
+        (if
+          (then
+            (i32.const DATA_SIZE)
+            (local.get $offset)
+            (i32.sub)
+            (local.set $length)
+          )
+        )
+
+        (local.get $buffer_ptr)
+        (local.get $offset)           ;; Source address (our data + offset)
+        (local.get $length)           ;; Size to copy
+        (call $respond)
+        (return)
+      )
+    )
+
+    ;; Opcode 1003 (GetContentType) returns the MIME type of the data
+    (local.get $opcode)
+    (i32.const 1003)
+    (i32.eq)
+    (if
+      (then
+        (local.get $buffer_ptr)
+        (i32.const CONTENT_TYPE_OFFSET)   ;; Source address (our content type)
+        (i32.const CONTENT_TYPE_SIZE)     ;; Size to copy - will be replaced by script
+        (call $respond)
+        (return)
+      )
+    )
+
+    ;; Opcode 1004 (GetContentHash) returns the SHA-256 hash of the data
+    (local.get $opcode)
+    (i32.const 1004)
+    (i32.eq)
+    (if
+      (then
+        (local.get $buffer_ptr)
+        (i32.const CONTENT_HASH_OFFSET)   ;; Source address (our content hash)
+        (i32.const 32)                    ;; A SHA-256 hash is 32 bytes
+        (call $respond)
+        (return)
+      )
+    )
+
+    ;; Any other opcode returns an empty response
+    (local.get $buffer_ptr)
+    (i32.const 0)
+    (i32.const 0)                     ;; No data
+    (call $respond)
   )
-)
\ No newline at end of file
+)
@@ -36,7 +36,7 @@ function generateRandomPng(filePath: string, width: number = 100, height: number
 function runCli(args: string[]): Promise<{ stdout: string; stderr: string; exitCode: number }> {
   return new Promise((resolve) => {
     // Get the path to the CLI script
-    const cliPath = path.resolve(__dirname, '../dist/cli.js');
+    const cliPath = path.resolve(__dirname, '../dist/src/cli.js');
     
     // Spawn the process
     const child = spawn('node', [cliPath, ...args], {
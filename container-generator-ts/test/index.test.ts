@@ -5,7 +5,8 @@ import {
   generateWat, 
   generateWasm, 
   generateContainerFromData,
-  defaultWat2Wasm
+  defaultWat2Wasm,
+  DEFAULT_CONTENT_TYPE
 } from '../src/index';
 
 describe('Container Generator Library Tests', () => {
@@ -42,6 +43,65 @@ describe('Container Generator Library Tests', () => {
       expect(wat).toContain('(export "__execute"');
     });
     
+    test('should size memory and scratch space for the data', () => {
+      // Create test data larger than a memory page
+      const testData = new Uint8Array(70000);
+      
+      // Generate WAT
+      const wat = generateWat(testData);
+      
+      // Check that every placeholder was replaced
+      expect(wat).not.toContain('DATA_SIZE');
+      expect(wat).not.toContain('SCRATCH_BASE');
+      expect(wat).not.toContain('MEMORY_PAGES');
+      
+      // The content hash sits right after the data, which is already 16-byte aligned
+      expect(wat).toContain('(data (i32.const 70000) "');
+      
+      // Scratch space starts after the content hash and content type, aligned to 16 bytes
+      const scratchBase = Math.ceil((70000 + 32 + DEFAULT_CONTENT_TYPE.length) / 16) * 16;
+      expect(scratchBase).toBe(70064);
+      expect(wat).toContain(`(i32.const ${scratchBase})          ;; Context buffer`);
+      
+      // Memory holds the data, a copy of it in the response and a page of scratch space
+      expect(wat).toContain('(memory (export "memory") 4)');
+    });
+    
+    test('should handle the chunked data opcodes', () => {
+      // Generate WAT
+      const wat = generateWat(new Uint8Array([1, 2, 3, 4, 5]));
+      
+      // Check that GetData, GetDataSize and GetDataRange are dispatched
+      expect(wat).toContain('(i32.const 1000)');
+      expect(wat).toContain('(i32.const 1001)');
+      expect(wat).toContain('(i32.const 1002)');
+    });
+    
+    test('should embed the content type and hash', () => {
+      // Generate WAT for "abc" with a content type
+      const testData = new TextEncoder().encode('abc');
+      const wat = generateWat(testData, { contentType: 'text/plain' });
+      
+      // The content hash is the SHA-256 of the data
+      expect(wat).toContain('"\\ba\\78\\16\\bf\\8f\\01\\cf\\ea\\41\\41\\40\\de\\5d\\ae\\22\\23\\b0\\03\\61\\a3\\96\\17\\7a\\9c\\b4\\10\\ff\\61\\f2\\00\\15\\ad"');
+      
+      // The content type follows the hash
+      expect(wat).toContain('(data (i32.const 16) "');
+      expect(wat).toContain('(data (i32.const 48) "\\74\\65\\78\\74\\2f\\70\\6c\\61\\69\\6e")');
+      expect(wat).toContain('(i32.const 10)');
+      
+      // Check that GetContentType and GetContentHash are dispatched
+      expect(wat).toContain('(i32.const 1003)');
+      expect(wat).toContain('(i32.const 1004)');
+    });
+    
+    test('should default the content type', () => {
+      const wat = generateWat(new Uint8Array([1, 2, 3]));
+      
+      // application/octet-stream
+      expect(wat).toContain('"\\61\\70\\70\\6c\\69\\63\\61\\74\\69\\6f\\6e\\2f');
+    });
+    
     test('should use a custom template if provided', () => {
       // Create test data
       const testData = new Uint8Array([1, 2, 3, 4, 5]);
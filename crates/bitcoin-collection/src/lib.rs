@@ -13,47 +13,12 @@ use alkanes_runtime::imports::__call;
 use metashrew_support::index_pointer::KeyValuePointer;
 use metashrew_support::compat::to_arraybuffer_layout;
 
-/// Orbital template ID - this is the template used for creating orbital instances
+/// Default orbital template ID - used when a collection is initialized without one
 pub const ORBITAL_TEMPLATE_ID: u128 = 0xe0e2;
 
 /// Bitcoin sale template ID - this is the template used for creating the bitcoin sale
 pub const BITCOIN_SALE_TEMPLATE_ID: u128 = 0xe0e3;
 
-/// TokenName struct to hold two u128 values for the name
-#[derive(Default, Clone, Copy)]
-pub struct TokenName {
-    pub part1: u128,
-    pub part2: u128,
-}
-
-impl From<TokenName> for String {
-    fn from(name: TokenName) -> Self {
-        // Trim both parts and concatenate them
-        format!("{}{}", trim(name.part1), trim(name.part2))
-    }
-}
-
-impl TokenName {
-    pub fn new(part1: u128, part2: u128) -> Self {
-        Self { part1, part2 }
-    }
-}
-
-/// Trims a u128 value to a String by removing trailing zeros
-pub fn trim(v: u128) -> String {
-    String::from_utf8(
-        v.to_le_bytes()
-            .into_iter()
-            .fold(Vec::<u8>::new(), |mut r, v| {
-                if v != 0 {
-                    r.push(v)
-                }
-                r
-            }),
-    )
-    .unwrap_or_default()
-}
-
 /// Bitcoin Collection alkane that acts as a factory for orbital instances
 /// Only allows the bitcoin-sale contract to mint orbitals
 #[derive(Default)]
@@ -65,12 +30,12 @@ enum BitcoinCollectionMessage {
     /// Initialize the collection
     #[opcode(0)]
     Initialize {
-        /// Name part 1
-        name_part1: u128,
-        /// Name part 2
-        name_part2: u128,
-        /// Symbol
-        symbol: u128,
+        /// Orbital template ID (0 for the default template)
+        orbital_template: u128,
+        /// Name (length-prefixed string)
+        name: String,
+        /// Symbol (length-prefixed string)
+        symbol: String,
     },
 
     /// Create a new orbital instance (only callable by authorized alkanes)
@@ -101,6 +66,31 @@ enum BitcoinCollectionMessage {
     #[opcode(1000)]
     #[returns(Vec<u8>)]
     GetData,
+
+    /// Get the size of the collection data in bytes
+    #[opcode(1001)]
+    #[returns(u128)]
+    GetDataSize,
+
+    /// Get the bytes of the collection data in [offset, offset + length)
+    #[opcode(1002)]
+    #[returns(Vec<u8>)]
+    GetDataRange {
+        /// Offset of the first byte
+        offset: u128,
+        /// Number of bytes to return, clamped to the end of the data
+        length: u128,
+    },
+
+    /// Get the MIME type of the collection data
+    #[opcode(1003)]
+    #[returns(String)]
+    GetContentType,
+
+    /// Get the SHA-256 hash of the collection data
+    #[opcode(1004)]
+    #[returns(Vec<u8>)]
+    GetContentHash,
 }
 
 impl Token for BitcoinCollection {
@@ -136,6 +126,16 @@ impl BitcoinCollection {
         self.name_pointer().set(Arc::new(name.as_bytes().to_vec()));
     }
 
+    /// Get the pointer to the orbital template ID
+    pub fn orbital_template_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/orbital-template")
+    }
+
+    /// Set the orbital template ID, where 0 selects the default template
+    pub fn set_orbital_template_id(&self, template: u128) {
+        self.orbital_template_pointer().set_value::<u128>(template);
+    }
+
     /// Get the pointer to the symbol
     pub fn symbol_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/symbol")
@@ -147,15 +147,9 @@ impl BitcoinCollection {
     }
 
     /// Set the name and symbol
-    pub fn set_name_and_symbol(&self, name: TokenName, symbol: u128) {
-        let name_string: String = name.into();
-        self.set_name(&name_string);
-        self.set_string_field(self.symbol_pointer(), symbol);
-    }
-
-    /// Set a string field in storage
-    fn set_string_field(&self, mut pointer: StoragePointer, v: u128) {
-        pointer.set(Arc::new(trim(v).as_bytes().to_vec()));
+    pub fn set_name_and_symbol(&self, name: &str, symbol: &str) {
+        self.set_name(name);
+        self.set_symbol(symbol);
     }
 
     /// Get the total supply
@@ -185,6 +179,29 @@ impl BitcoinCollection {
         self.container_sequence_pointer().set_value::<u128>(sequence);
     }
 
+    /// Call a data opcode on the container and return the response bytes
+    pub fn container_data(&self, inputs: Vec<u128>) -> Result<Vec<u8>> {
+        // The container is at AlkaneId { block: 2, tx: self.container_sequence() }
+        let container_id = AlkaneId {
+            block: 2,
+            tx: self.container_sequence(),
+        };
+        
+        let cellpack = Cellpack {
+            target: container_id,
+            inputs,
+        };
+        
+        // Call the container
+        let call_response = self.staticcall(
+            &cellpack,
+            &AlkaneTransferParcel::default(),
+            self.fuel()
+        )?;
+
+        Ok(call_response.data)
+    }
+
     /// Get the pointer to the instances registry
     pub fn instances_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/instances")
@@ -333,7 +350,10 @@ impl BitcoinCollection {
 impl OrbitalCollection for BitcoinCollection {
     /// Get the orbital template ID
     fn orbital_template(&self) -> u128 {
-        ORBITAL_TEMPLATE_ID
+        match self.orbital_template_pointer().get_value::<u128>() {
+            0 => ORBITAL_TEMPLATE_ID,
+            template => template,
+        }
     }
     
     /// Check if an alkane ID is authorized to create orbitals
@@ -347,16 +367,18 @@ impl OrbitalCollection for BitcoinCollection {
     }
     
     /// Initialize the collection
-    fn initialize(&self, name_part1: u128, name_part2: u128, symbol: u128) -> Result<CallResponse> {
+    fn initialize(&self, orbital_template: u128, name: String, symbol: String) -> Result<CallResponse> {
         let context = self.context()?;
         let response = CallResponse::forward(&context.incoming_alkanes);
 
         // Prevent multiple initializations
         self.observe_initialization()?;
 
-        // Create TokenName from the two parts
-        let name = TokenName::new(name_part1, name_part2);
-        self.set_name_and_symbol(name, symbol);
+        // Set the name and symbol
+        self.set_name_and_symbol(&name, &symbol);
+
+        // Set the orbital template used for new mints
+        self.set_orbital_template_id(orbital_template);
 
         // Initialize the instances count
         self.set_instances_count(0);
@@ -423,12 +445,6 @@ impl OrbitalCollection for BitcoinCollection {
             return Err(anyhow!("Unauthorized caller"));
         }
 
-        // Get the next sequence number
-        let sequence = match self.context() {
-            Ok(context) => context.myself.tx,
-            Err(_) => 0, // This should never happen
-        };
-
         // Get the next index (0-based)
         let index = self.instances_count();
 
@@ -441,24 +457,30 @@ impl OrbitalCollection for BitcoinCollection {
             inputs: vec![0, index], // Initialize opcode with index
         };
         
-        // Call the orbital template but ignore the response
-        let _orbital_call_response = self.call(
+        // Call the orbital template, which mints the single unit of the new orbital back to us
+        let orbital_call_response = self.call(
             &orbital_cellpack,
             &AlkaneTransferParcel::default(),
             self.fuel()
         )?;
         
-        // Extract the orbital instance ID from the response
-        // In a real implementation, we would parse the response to get the ID
-        // For now, we'll just use a simplified ID
-        let instance_id = AlkaneId {
-            block: 2, // Simplified for demonstration
-            tx: sequence,
-        };
+        // The minted unit in the returned parcel carries the AlkaneId of the new instance,
+        // so anything other than exactly one transfer of one unit is not a valid mint
+        let transfers = orbital_call_response.alkanes.0;
+        if transfers.len() != 1 || transfers[0].value != 1 {
+            return Err(anyhow!(
+                "orbital factory call must return exactly one transfer of 1 unit, got {} transfers",
+                transfers.len()
+            ));
+        }
+        let instance_id = transfers[0].id.clone();
         
         // Add the instance to the registry
         self.add_instance(&instance_id)?;
 
+        // Hand the orbital to the caller so it can deliver it to the buyer
+        response.alkanes.0.push(orbital_transfer);
+
         // Serialize the instance ID and index
         let mut bytes = Vec::with_capacity(48);
         bytes.extend_from_slice(&instance_id.block.to_le_bytes());
@@ -516,27 +538,49 @@ impl OrbitalCollection for BitcoinCollection {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        // Create a cellpack to call the container's GetData opcode
-        // The container is at AlkaneId { block: 2, tx: self.container_sequence() }
-        let container_id = AlkaneId {
-            block: 2,
-            tx: self.container_sequence(),
-        };
-        
-        let cellpack = Cellpack {
-            target: container_id,
-            inputs: vec![1000], // GetData opcode
-        };
-        
-        // Call the container's GetData opcode
-        let call_response = self.staticcall(
-            &cellpack,
-            &AlkaneTransferParcel::default(),
-            self.fuel()
-        )?;
-        
         // Pass the bytes with NO transform to the caller
-        response.data = call_response.data;
+        response.data = self.container_data(vec![1000])?; // GetData opcode
+
+        Ok(response)
+    }
+
+    /// Get the size of the collection data
+    fn get_data_size(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.container_data(vec![1001])?; // GetDataSize opcode
+
+        Ok(response)
+    }
+
+    /// Get a range of the collection data
+    fn get_data_range(&self, offset: u128, length: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        // Pass the bytes with NO transform to the caller
+        response.data = self.container_data(vec![1002, offset, length])?; // GetDataRange opcode
+
+        Ok(response)
+    }
+
+    /// Get the MIME type of the collection data
+    fn get_content_type(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.container_data(vec![1003])?; // GetContentType opcode
+
+        Ok(response)
+    }
+
+    /// Get the SHA-256 hash of the collection data
+    fn get_content_hash(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.container_data(vec![1004])?; // GetContentHash opcode
 
         Ok(response)
     }
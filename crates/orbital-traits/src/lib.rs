@@ -12,7 +12,7 @@ pub trait OrbitalCollection {
     fn is_authorized(&self, alkane_id: &AlkaneId) -> bool;
     
     /// Initialize the collection
-    fn initialize(&self, name_part1: u128, name_part2: u128, symbol: u128) -> Result<CallResponse>;
+    fn initialize(&self, orbital_template: u128, name: String, symbol: String) -> Result<CallResponse>;
     
     /// Create a new orbital instance
     fn create_orbital(&self) -> Result<CallResponse>;
@@ -31,6 +31,18 @@ pub trait OrbitalCollection {
     
     /// Get the data of the collection
     fn get_data(&self) -> Result<CallResponse>;
+    
+    /// Get the size of the collection data
+    fn get_data_size(&self) -> Result<CallResponse>;
+    
+    /// Get the bytes of the collection data in [offset, offset + length)
+    fn get_data_range(&self, offset: u128, length: u128) -> Result<CallResponse>;
+    
+    /// Get the MIME type of the collection data
+    fn get_content_type(&self) -> Result<CallResponse>;
+    
+    /// Get the SHA-256 hash of the collection data
+    fn get_content_hash(&self) -> Result<CallResponse>;
 }
 
 /// A trait for customizable orbitals
@@ -52,6 +64,18 @@ pub trait OrbitalInstance {
     
     /// Get the data of the orbital
     fn get_data(&self) -> Result<CallResponse>;
+    
+    /// Get the size of the orbital's data
+    fn get_data_size(&self) -> Result<CallResponse>;
+    
+    /// Get the bytes of the orbital's data in [offset, offset + length)
+    fn get_data_range(&self, offset: u128, length: u128) -> Result<CallResponse>;
+    
+    /// Get the MIME type of the orbital's data
+    fn get_content_type(&self) -> Result<CallResponse>;
+    
+    /// Get the SHA-256 hash of the orbital's data
+    fn get_content_hash(&self) -> Result<CallResponse>;
 }
 
 /// Message opcodes for orbital collections
@@ -63,6 +87,10 @@ pub mod collection_opcodes {
     pub const GET_TOTAL_SUPPLY: u128 = 101;
     pub const GET_ORBITAL_COUNT: u128 = 102;
     pub const GET_DATA: u128 = 1000;
+    pub const GET_DATA_SIZE: u128 = 1001;
+    pub const GET_DATA_RANGE: u128 = 1002;
+    pub const GET_CONTENT_TYPE: u128 = 1003;
+    pub const GET_CONTENT_HASH: u128 = 1004;
 }
 
 /// Message opcodes for orbital instances
@@ -72,4 +100,8 @@ pub mod orbital_opcodes {
     pub const GET_SYMBOL: u128 = 100;
     pub const GET_TOTAL_SUPPLY: u128 = 101;
     pub const GET_DATA: u128 = 1000;
+    pub const GET_DATA_SIZE: u128 = 1001;
+    pub const GET_DATA_RANGE: u128 = 1002;
+    pub const GET_CONTENT_TYPE: u128 = 1003;
+    pub const GET_CONTENT_HASH: u128 = 1004;
 }
\ No newline at end of file
@@ -184,15 +184,15 @@ pub fn derive_orbital_collection_message(input: TokenStream) -> TokenStream {
                 use orbital_traits::collection_opcodes::*;
                 match opcode {
                     INITIALIZE => {
-                        if inputs.len() >= 3 {
-                            Ok(Self::Initialize {
-                                name_part1: inputs[0],
-                                name_part2: inputs[1],
-                                symbol: inputs[2],
-                            })
-                        } else {
-                            Err(anyhow::anyhow!("Missing parameters for Initialize"))
-                        }
+                        // The orbital template is followed by the name and symbol as length-prefixed strings
+                        let mut reader = orbitals_support::codec::InputReader::new(&inputs);
+                        let orbital_template = reader.next_u128()
+                            .map_err(|_| anyhow::anyhow!("Missing orbital template for Initialize"))?;
+                        let name = reader.next_string()
+                            .map_err(|e| anyhow::anyhow!("Invalid name for Initialize: {}", e))?;
+                        let symbol = reader.next_string()
+                            .map_err(|e| anyhow::anyhow!("Invalid symbol for Initialize: {}", e))?;
+                        Ok(Self::Initialize { orbital_template, name, symbol })
                     },
                     CREATE_ORBITAL => Ok(Self::CreateOrbital),
                     GET_NAME => Ok(Self::GetName),
@@ -200,14 +200,24 @@ pub fn derive_orbital_collection_message(input: TokenStream) -> TokenStream {
                     GET_TOTAL_SUPPLY => Ok(Self::GetTotalSupply),
                     GET_ORBITAL_COUNT => Ok(Self::GetOrbitalCount),
                     GET_DATA => Ok(Self::GetData),
+                    GET_DATA_SIZE => Ok(Self::GetDataSize),
+                    GET_DATA_RANGE => {
+                        if inputs.len() >= 2 {
+                            Ok(Self::GetDataRange { offset: inputs[0], length: inputs[1] })
+                        } else {
+                            Err(anyhow::anyhow!("Missing offset and length parameters for GetDataRange"))
+                        }
+                    },
+                    GET_CONTENT_TYPE => Ok(Self::GetContentType),
+                    GET_CONTENT_HASH => Ok(Self::GetContentHash),
                     _ => Err(anyhow::anyhow!("Unknown opcode: {}", opcode)),
                 }
             }
             
             pub fn dispatch(&self, responder: &BitcoinCollection) -> Result<alkanes_support::response::CallResponse, anyhow::Error> {
                 match self {
-                    Self::Initialize { name_part1, name_part2, symbol } => {
-                        responder.initialize(*name_part1, *name_part2, *symbol)
+                    Self::Initialize { orbital_template, name, symbol } => {
+                        responder.initialize(*orbital_template, name.clone(), symbol.clone())
                     },
                     Self::CreateOrbital => responder.create_orbital(),
                     Self::GetName => responder.get_name(),
@@ -215,6 +225,10 @@ pub fn derive_orbital_collection_message(input: TokenStream) -> TokenStream {
                     Self::GetTotalSupply => responder.get_total_supply(),
                     Self::GetOrbitalCount => responder.get_orbital_count(),
                     Self::GetData => responder.get_data(),
+                    Self::GetDataSize => responder.get_data_size(),
+                    Self::GetDataRange { offset, length } => responder.get_data_range(*offset, *length),
+                    Self::GetContentType => responder.get_content_type(),
+                    Self::GetContentHash => responder.get_content_hash(),
                 }
             }
             
@@ -225,13 +239,17 @@ pub fn derive_orbital_collection_message(input: TokenStream) -> TokenStream {
             
             pub fn export_abi() -> Vec<u8> {
                 r#"[
-                    {"opcode":0,"name":"Initialize","inputs":[{"name":"name_part1","type":"u128"},{"name":"name_part2","type":"u128"},{"name":"symbol","type":"u128"}],"outputs":[]},
+                    {"opcode":0,"name":"Initialize","inputs":[{"name":"orbital_template","type":"u128"},{"name":"name","type":"String"},{"name":"symbol","type":"String"}],"outputs":[]},
                     {"opcode":77,"name":"CreateOrbital","inputs":[],"outputs":[]},
                     {"opcode":99,"name":"GetName","inputs":[],"outputs":[{"type":"String"}]},
                     {"opcode":100,"name":"GetSymbol","inputs":[],"outputs":[{"type":"String"}]},
                     {"opcode":101,"name":"GetTotalSupply","inputs":[],"outputs":[{"type":"u128"}]},
                     {"opcode":102,"name":"GetOrbitalCount","inputs":[],"outputs":[{"type":"u128"}]},
-                    {"opcode":1000,"name":"GetData","inputs":[],"outputs":[{"type":"Vec<u8>"}]}
+                    {"opcode":1000,"name":"GetData","inputs":[],"outputs":[{"type":"Vec<u8>"}]},
+                    {"opcode":1001,"name":"GetDataSize","inputs":[],"outputs":[{"type":"u128"}]},
+                    {"opcode":1002,"name":"GetDataRange","inputs":[{"name":"offset","type":"u128"},{"name":"length","type":"u128"}],"outputs":[{"type":"Vec<u8>"}]},
+                    {"opcode":1003,"name":"GetContentType","inputs":[],"outputs":[{"type":"String"}]},
+                    {"opcode":1004,"name":"GetContentHash","inputs":[],"outputs":[{"type":"Vec<u8>"}]}
                 ]"#.as_bytes().to_vec()
             }
         }
@@ -267,6 +285,16 @@ pub fn derive_orbital_instance_message(input: TokenStream) -> TokenStream {
                     GET_SYMBOL => Ok(Self::GetSymbol),
                     GET_TOTAL_SUPPLY => Ok(Self::GetTotalSupply),
                     GET_DATA => Ok(Self::GetData),
+                    GET_DATA_SIZE => Ok(Self::GetDataSize),
+                    GET_DATA_RANGE => {
+                        if inputs.len() >= 2 {
+                            Ok(Self::GetDataRange { offset: inputs[0], length: inputs[1] })
+                        } else {
+                            Err(anyhow::anyhow!("Missing offset and length parameters for GetDataRange"))
+                        }
+                    },
+                    GET_CONTENT_TYPE => Ok(Self::GetContentType),
+                    GET_CONTENT_HASH => Ok(Self::GetContentHash),
                     _ => Err(anyhow::anyhow!("Unknown opcode: {}", opcode)),
                 }
             }
@@ -281,6 +309,10 @@ pub fn derive_orbital_instance_message(input: TokenStream) -> TokenStream {
                     Self::GetSymbol => responder.get_symbol(),
                     Self::GetTotalSupply => responder.get_total_supply(),
                     Self::GetData => responder.get_data(),
+                    Self::GetDataSize => responder.get_data_size(),
+                    Self::GetDataRange { offset, length } => responder.get_data_range(*offset, *length),
+                    Self::GetContentType => responder.get_content_type(),
+                    Self::GetContentHash => responder.get_content_hash(),
                 }
             }
             
@@ -298,7 +330,11 @@ pub fn derive_orbital_instance_message(input: TokenStream) -> TokenStream {
                     {"opcode":99,"name":"GetName","inputs":[],"outputs":[{"type":"String"}]},
                     {"opcode":100,"name":"GetSymbol","inputs":[],"outputs":[{"type":"String"}]},
                     {"opcode":101,"name":"GetTotalSupply","inputs":[],"outputs":[{"type":"u128"}]},
-                    {"opcode":1000,"name":"GetData","inputs":[],"outputs":[{"type":"Vec<u8>"}]}
+                    {"opcode":1000,"name":"GetData","inputs":[],"outputs":[{"type":"Vec<u8>"}]},
+                    {"opcode":1001,"name":"GetDataSize","inputs":[],"outputs":[{"type":"u128"}]},
+                    {"opcode":1002,"name":"GetDataRange","inputs":[{"name":"offset","type":"u128"},{"name":"length","type":"u128"}],"outputs":[{"type":"Vec<u8>"}]},
+                    {"opcode":1003,"name":"GetContentType","inputs":[],"outputs":[{"type":"String"}]},
+                    {"opcode":1004,"name":"GetContentHash","inputs":[],"outputs":[{"type":"Vec<u8>"}]}
                 ]"#.as_bytes().to_vec()
             }
         }
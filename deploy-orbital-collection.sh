@@ -382,8 +382,8 @@ check_account_balances
 # Deploy container
 log_step "Deploying container"
 log_info "Contract path: $CONTAINER_OUTPUT_PATH"
-log_info "Calldata: 6,$COLLECTION_CONSTANT,0,357879337540,0,357879337540"
-CONTAINER_OUTPUT=$(oyl alkane new-contract -c "$CONTAINER_OUTPUT_PATH" --calldata "6,$COLLECTION_CONSTANT,0,357879337540,0,357879337540" --feeRate $FEE_RATE -p alkanes)
+log_info "Calldata: 6,$COLLECTION_CONSTANT,0,$ORBITAL_CONSTANT,5,357879337540,5,357879337540"
+CONTAINER_OUTPUT=$(oyl alkane new-contract -c "$CONTAINER_OUTPUT_PATH" --calldata "6,$COLLECTION_CONSTANT,0,$ORBITAL_CONSTANT,5,357879337540,5,357879337540" --feeRate $FEE_RATE -p alkanes)
 CONTAINER_TXID=$(extract_txid "$CONTAINER_OUTPUT")
 log_info "Container deployed with txid: $CONTAINER_TXID"
 wait_after_transaction
@@ -400,10 +400,9 @@ log_success "Deployment verified"
 log_step "Deploying bitcoin sale instance"
 
 # Use simpler values for the bitcoin sale instance
-# Name and symbol for the collection
-NAME_PART1="4276772" # "ABC" in decimal
-NAME_PART2="0"
-SYMBOL="4276772" # "ABC" in decimal
+# Name and symbol for the collection, each as a byte length followed by the packed bytes
+NAME="3,4407873" # "ABC"
+SYMBOL="3,4407873" # "ABC"
 
 # Use a fixed payment address (the taproot address)
 # Instead of trying to convert the address to decimal, we'll use a fixed value
@@ -416,8 +415,8 @@ check_account_balances
 
 # Deploy bitcoin sale instance with the container
 log_info "Contract path: $BITCOIN_SALE_CONTRACT_PATH"
-log_info "Calldata: 6,$BITCOIN_SALE_CONSTANT,0,$BTC_PRICE_SATS,1000,$PAYMENT_ADDR_PART1,$PAYMENT_ADDR_PART2,$PAYMENT_ADDR_PART3,$NAME_PART1,$NAME_PART2,$SYMBOL"
-BITCOIN_SALE_INSTANCE_OUTPUT=$(oyl alkane new-contract -c "$BITCOIN_SALE_CONTRACT_PATH" --calldata "6,$BITCOIN_SALE_CONSTANT,0,$BTC_PRICE_SATS,1000,$PAYMENT_ADDR_PART1,$PAYMENT_ADDR_PART2,$PAYMENT_ADDR_PART3,$NAME_PART1,$NAME_PART2,$SYMBOL" --feeRate $FEE_RATE -p alkanes)
+log_info "Calldata: 6,$BITCOIN_SALE_CONSTANT,0,$BTC_PRICE_SATS,1000,$PAYMENT_ADDR_PART1,$PAYMENT_ADDR_PART2,$PAYMENT_ADDR_PART3,$ORBITAL_CONSTANT,$NAME,$SYMBOL"
+BITCOIN_SALE_INSTANCE_OUTPUT=$(oyl alkane new-contract -c "$BITCOIN_SALE_CONTRACT_PATH" --calldata "6,$BITCOIN_SALE_CONSTANT,0,$BTC_PRICE_SATS,1000,$PAYMENT_ADDR_PART1,$PAYMENT_ADDR_PART2,$PAYMENT_ADDR_PART3,$ORBITAL_CONSTANT,$NAME,$SYMBOL" --feeRate $FEE_RATE -p alkanes)
 BITCOIN_SALE_INSTANCE_TXID=$(extract_txid "$BITCOIN_SALE_INSTANCE_OUTPUT")
 log_info "Bitcoin sale instance deployed with txid: $BITCOIN_SALE_INSTANCE_TXID"
 wait_after_transaction
@@ -0,0 +1,206 @@
+// merkle-allowlist.js
+// This script builds a Merkle allowlist for the sale alkanes from a CSV of buyers
+
+const fs = require('fs');
+const crypto = require('crypto');
+
+// Purchase section tag for a Merkle proof (see purchase_tags in the sale alkanes)
+const MERKLE_PROOF_TAG = 1n;
+
+// Parse command line arguments
+const args = process.argv.slice(2);
+const argMap = {};
+for (let i = 0; i < args.length; i++) {
+  if (args[i].startsWith('--')) {
+    const key = args[i].substring(2);
+    const value = args[i+1] && !args[i+1].startsWith('--') ? args[i+1] : true;
+    argMap[key] = value;
+    if (value !== true) i++;
+  }
+}
+
+// Required parameters
+const csvPath = argMap['csv'];
+const outPath = argMap['out'];
+
+if (!csvPath) {
+  console.error('Usage: node merkle-allowlist.js --csv <allowlist.csv> [--out <allowlist.json>]');
+  console.error('Each CSV line is <address or script_pubkey hex>,<max quantity>');
+  process.exit(1);
+}
+
+function sha256(data) {
+  return crypto.createHash('sha256').update(data).digest();
+}
+
+// Decode a bech32 or bech32m segwit address into its output script
+const BECH32_CHARSET = 'qpzry9x8gf2tvdw0s3jn54khce6mua7l';
+
+function bech32Polymod(values) {
+  const generators = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+  let chk = 1;
+  for (const value of values) {
+    const top = chk >>> 25;
+    chk = ((chk & 0x1ffffff) << 5) ^ value;
+    for (let i = 0; i < 5; i++) {
+      if ((top >>> i) & 1) {
+        chk ^= generators[i];
+      }
+    }
+  }
+  return chk >>> 0;
+}
+
+function segwitScript(address) {
+  const lower = address.toLowerCase();
+  const separator = lower.lastIndexOf('1');
+  if (separator < 1) {
+    throw new Error(`Not a segwit address: ${address}`);
+  }
+
+  const hrp = lower.substring(0, separator);
+  const data = [...lower.substring(separator + 1)].map((c) => {
+    const value = BECH32_CHARSET.indexOf(c);
+    if (value === -1) {
+      throw new Error(`Invalid character in address: ${address}`);
+    }
+    return value;
+  });
+
+  // Check the checksum: bech32 for version 0, bech32m for later versions
+  const expanded = [...hrp].map((c) => c.charCodeAt(0) >> 5)
+    .concat([0])
+    .concat([...hrp].map((c) => c.charCodeAt(0) & 31))
+    .concat(data);
+  const version = data[0];
+  const constant = version === 0 ? 1 : 0x2bc830a3;
+  if (bech32Polymod(expanded) !== constant) {
+    throw new Error(`Invalid checksum in address: ${address}`);
+  }
+
+  // Convert the 5-bit program words to bytes
+  const program = [];
+  let accumulator = 0;
+  let bits = 0;
+  for (const value of data.slice(1, -6)) {
+    accumulator = (accumulator << 5) | value;
+    bits += 5;
+    if (bits >= 8) {
+      bits -= 8;
+      program.push((accumulator >> bits) & 0xff);
+    }
+  }
+
+  const opcode = version === 0 ? 0x00 : 0x50 + version;
+  return Buffer.from([opcode, program.length, ...program]);
+}
+
+// Get the output script for a CSV entry: raw script hex or a segwit address
+function outputScript(value) {
+  if (/^[0-9a-fA-F]+$/.test(value) && value.length % 2 === 0) {
+    return Buffer.from(value, 'hex');
+  }
+  return segwitScript(value);
+}
+
+// Split a 32-byte hash into the two u128 inputs the alkanes read (little-endian halves)
+function hashToInputs(hash) {
+  const half = (bytes) => BigInt('0x' + (Buffer.from(bytes).reverse().toString('hex') || '0'));
+  return [half(hash.subarray(0, 16)), half(hash.subarray(16, 32))];
+}
+
+// Leaf: sha256(buyer_key || max_quantity as 16 little-endian bytes)
+function leafHash(buyerKey, maxQuantity) {
+  const quantity = Buffer.alloc(16);
+  quantity.writeBigUInt64LE(maxQuantity & 0xffffffffffffffffn, 0);
+  quantity.writeBigUInt64LE(maxQuantity >> 64n, 8);
+  return sha256(Buffer.concat([buyerKey, quantity]));
+}
+
+// Parent: sha256 of the two children in ascending byte order
+function hashPair(a, b) {
+  return Buffer.compare(a, b) <= 0 ? sha256(Buffer.concat([a, b])) : sha256(Buffer.concat([b, a]));
+}
+
+// Hash each pair into the level above, promoting an unpaired last node
+function nextLevel(level) {
+  const next = [];
+  for (let i = 0; i < level.length; i += 2) {
+    next.push(i + 1 < level.length ? hashPair(level[i], level[i + 1]) : level[i]);
+  }
+  return next;
+}
+
+function buildProof(leaves, index) {
+  const proof = [];
+  let level = leaves;
+  while (level.length > 1) {
+    const sibling = index ^ 1;
+    if (sibling < level.length) {
+      proof.push(level[sibling]);
+    }
+    level = nextLevel(level);
+    index = Math.floor(index / 2);
+  }
+  return proof;
+}
+
+function main() {
+  try {
+    // Read the buyers, skipping blank lines, comments and a header row
+    const entries = fs.readFileSync(csvPath, 'utf8')
+      .split(/\r?\n/)
+      .map((line) => line.trim())
+      .filter((line) => line && !line.startsWith('#'))
+      .map((line) => line.split(',').map((field) => field.trim()))
+      .filter(([, quantity]) => /^\d+$/.test(quantity || ''))
+      .map(([buyer, quantity]) => {
+        const script = outputScript(buyer);
+        return { buyer, script, key: sha256(script), maxQuantity: BigInt(quantity) };
+      });
+
+    if (entries.length === 0) {
+      throw new Error(`No allowlist entries found in ${csvPath}`);
+    }
+
+    const leaves = entries.map((entry) => leafHash(entry.key, entry.maxQuantity));
+    let level = leaves;
+    while (level.length > 1) {
+      level = nextLevel(level);
+    }
+    const root = level[0];
+
+    const output = {
+      root: root.toString('hex'),
+      rootInputs: hashToInputs(root).map(String),
+      entries: entries.map((entry, index) => {
+        const proof = buildProof(leaves, index);
+        const purchaseInputs = [MERKLE_PROOF_TAG, entry.maxQuantity, BigInt(proof.length)]
+          .concat(proof.flatMap(hashToInputs));
+        return {
+          buyer: entry.buyer,
+          script: entry.script.toString('hex'),
+          buyerKey: entry.key.toString('hex'),
+          buyerKeyInputs: hashToInputs(entry.key).map(String),
+          maxQuantity: entry.maxQuantity.toString(),
+          proof: proof.map((hash) => hash.toString('hex')),
+          purchaseInputs: purchaseInputs.join(','),
+        };
+      }),
+    };
+
+    const json = JSON.stringify(output, null, 2);
+    if (outPath) {
+      fs.writeFileSync(outPath, json);
+      console.log(`Allowlist of ${entries.length} buyers written to ${outPath}`);
+      console.log(`Merkle root: ${output.root} (inputs ${output.rootInputs.join(',')})`);
+    } else {
+      console.log(json);
+    }
+  } catch (error) {
+    console.error('Error:', error.message);
+    process.exit(1);
+  }
+}
+
+main();